@@ -4,27 +4,58 @@ pub(crate) mod inner {
         Class(String),
         TagName(String),
         QuerySelector(String),
+        XPath(String),
+        LinkText(String),
+        PartialLinkText(String),
+        Within(Box<By>, Box<By>),
+        Shadow(Box<By>, Box<By>),
     }
 
-    impl core::fmt::Display for By {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    impl By {
+        /// Whether the element is searched inside a shadow root, whose changes
+        /// are not observed from the document.
+        pub(crate) fn crosses_shadow_boundary(&self) -> bool {
             match self {
-                By::Id(id) => write!(f, "HTML element with id '{}' (`{:?}`)", id, &self),
-                By::Class(class) => {
-                    write!(f, "HTML element with class '{}' (`{:?}`)", class, &self)
+                By::Within(parent, child) => {
+                    parent.crosses_shadow_boundary() || child.crosses_shadow_boundary()
                 }
-                By::TagName(tag_name) => write!(
-                    f,
-                    "HTML element with tag name '{}' (`{:?}`)",
-                    tag_name, &self
-                ),
-                By::QuerySelector(selector) => write!(
-                    f,
-                    "HTML element queried with selector '{}' (`{:?}`)",
-                    selector, &self
-                ),
+                By::Shadow(_, _) => true,
+                _ => false,
             }
         }
+
+        fn fmt_description(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            match self {
+                By::Id(id) => write!(f, "HTML element with id '{id}'"),
+                By::Class(class) => write!(f, "HTML element with class '{class}'"),
+                By::TagName(tag_name) => write!(f, "HTML element with tag name '{tag_name}'"),
+                By::QuerySelector(selector) => {
+                    write!(f, "HTML element queried with selector '{selector}'")
+                }
+                By::XPath(expression) => {
+                    write!(f, "HTML element located by XPath expression '{expression}'")
+                }
+                By::LinkText(text) => write!(f, "HTML link with text '{text}'"),
+                By::PartialLinkText(text) => write!(f, "HTML link containing the text '{text}'"),
+                By::Within(parent, child) => {
+                    child.fmt_description(f)?;
+                    write!(f, " inside ")?;
+                    parent.fmt_description(f)
+                }
+                By::Shadow(host, inner) => {
+                    inner.fmt_description(f)?;
+                    write!(f, " inside the shadow root of ")?;
+                    host.fmt_description(f)
+                }
+            }
+        }
+    }
+
+    impl core::fmt::Display for By {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            self.fmt_description(f)?;
+            write!(f, " (`{self:?}`)")
+        }
     }
 
     impl core::fmt::Debug for By {
@@ -34,6 +65,11 @@ pub(crate) mod inner {
                 By::Class(class) => write!(f, "By::Class({class:?})"),
                 By::TagName(tag_name) => write!(f, "By::TagName({tag_name:?})"),
                 By::QuerySelector(selector) => write!(f, "By::QuerySelector({selector:?})"),
+                By::XPath(expression) => write!(f, "By::XPath({expression:?})"),
+                By::LinkText(text) => write!(f, "By::LinkText({text:?})"),
+                By::PartialLinkText(text) => write!(f, "By::PartialLinkText({text:?})"),
+                By::Within(parent, child) => write!(f, "By::Within({parent:?}, {child:?})"),
+                By::Shadow(host, inner) => write!(f, "By::Shadow({host:?}, {inner:?})"),
             }
         }
     }
@@ -70,4 +106,91 @@ pub mod By {
     pub fn QuerySelector(selector: impl Into<String>) -> inner::By {
         inner::By::QuerySelector(selector.into())
     }
+
+    /// Selects an element by an XPath expression.
+    ///
+    /// The first element in document order matched by the expression is
+    /// selected.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{By, Wait};
+    ///
+    /// Wait(1).until(By::XPath("//button[normalize-space()='Save']"));
+    /// ```
+    #[inline]
+    pub fn XPath(expression: impl Into<String>) -> inner::By {
+        inner::By::XPath(expression.into())
+    }
+
+    /// Selects an `<a>` element whose visible text is equal to the given text.
+    ///
+    /// The `innerText` of the element is trimmed before comparing.
+    #[inline]
+    pub fn LinkText(text: impl Into<String>) -> inner::By {
+        inner::By::LinkText(text.into())
+    }
+
+    /// Selects an `<a>` element whose visible text contains the given text.
+    #[inline]
+    pub fn PartialLinkText(text: impl Into<String>) -> inner::By {
+        inner::By::PartialLinkText(text.into())
+    }
+
+    /// Selects an element with the `child` selector inside the first element
+    /// selected by the `parent` selector.
+    ///
+    /// An XPath `child` selector must be relative to the parent, like
+    /// `.//button`, because an absolute one like `//button` would search the
+    /// whole document.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{By, Ec, Wait};
+    ///
+    /// Wait(1).until((
+    ///     By::Within(By::QuerySelector(".card:nth-of-type(3)"), By::Class("price")),
+    ///     Ec::InnerTextContains("$"),
+    /// ));
+    /// ```
+    #[inline]
+    pub fn Within(parent: inner::By, child: inner::By) -> inner::By {
+        inner::By::Within(Box::new(parent), Box::new(child))
+    }
+
+    /// Selects an element with the `inner` selector inside the open shadow
+    /// root of the first element selected by the `host` selector.
+    ///
+    /// Nest them to pierce several levels of shadow roots. Closed shadow
+    /// roots are not reachable.
+    ///
+    /// An XPath `inner` selector must be relative to the shadow root, like
+    /// `.//button`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{By, Wait};
+    ///
+    /// Wait(1).until(By::Shadow(
+    ///     By::TagName("my-app"),
+    ///     By::Shadow(By::TagName("my-dialog"), By::QuerySelector("button")),
+    /// ));
+    /// ```
+    #[inline]
+    pub fn Shadow(host: inner::By, inner: inner::By) -> inner::By {
+        inner::By::Shadow(Box::new(host), Box::new(inner))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_within_chain() {
+        assert_eq!(
+            By::Within(By::Id("dialog"), By::TagName("button")).to_string(),
+            concat!(
+                "HTML element with tag name 'button' inside HTML element with id 'dialog'",
+                r#" (`By::Within(By::Id("dialog"), By::TagName("button"))`)"#,
+            )
+        );
+    }
 }
@@ -0,0 +1,149 @@
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// Namespace of the HTML elements.
+const XHTML_NAMESPACE: &str = "http://www.w3.org/1999/xhtml";
+
+/// Object over which expected conditions are checked.
+///
+/// Objects found inside a frame belong to the JavaScript realm of the frame,
+/// so they are not instances of the constructors of the main window and
+/// `JsCast::dyn_into` rejects them. These types are recognized by their
+/// structure instead.
+pub(crate) trait Object: JsCast {
+    /// Whether the value is an object of this type from any realm.
+    fn is_object(value: &JsValue) -> bool;
+}
+
+impl Object for web_sys::Node {
+    fn is_object(value: &JsValue) -> bool {
+        node_type(value).is_some()
+    }
+}
+
+impl Object for web_sys::Element {
+    fn is_object(value: &JsValue) -> bool {
+        node_type(value) == Some(web_sys::Node::ELEMENT_NODE)
+    }
+}
+
+impl Object for web_sys::HtmlElement {
+    fn is_object(value: &JsValue) -> bool {
+        web_sys::Element::is_object(value)
+            && value
+                .unchecked_ref::<web_sys::Element>()
+                .namespace_uri()
+                .as_deref()
+                == Some(XHTML_NAMESPACE)
+    }
+}
+
+impl Object for web_sys::Document {
+    fn is_object(value: &JsValue) -> bool {
+        node_type(value) == Some(web_sys::Node::DOCUMENT_NODE)
+    }
+}
+
+impl Object for web_sys::ShadowRoot {
+    fn is_object(value: &JsValue) -> bool {
+        node_type(value) == Some(web_sys::Node::DOCUMENT_FRAGMENT_NODE)
+            && class_name(value).as_deref() == Some("ShadowRoot")
+    }
+}
+
+impl Object for web_sys::Storage {
+    fn is_object(value: &JsValue) -> bool {
+        class_name(value).as_deref() == Some("Storage")
+    }
+}
+
+impl Object for web_sys::Location {
+    fn is_object(value: &JsValue) -> bool {
+        class_name(value).as_deref() == Some("Location")
+    }
+}
+
+impl Object for js_sys::Array {
+    fn is_object(value: &JsValue) -> bool {
+        js_sys::Array::is_array(value)
+    }
+}
+
+/// Cast a value to an object type, whatever realm it belongs to.
+pub(crate) fn cast<T, V>(value: V) -> Option<T>
+where
+    T: Object,
+    V: Into<JsValue>,
+{
+    let value = value.into();
+    T::is_object(&value).then(|| value.unchecked_into())
+}
+
+/// Reference a value as an object type, whatever realm it belongs to.
+pub(crate) fn cast_ref<T>(value: &JsValue) -> Option<&T>
+where
+    T: Object,
+{
+    T::is_object(value).then(|| value.unchecked_ref())
+}
+
+/// Reference an element as the HTML element with the given local name, like
+/// `input`, whatever realm it belongs to.
+pub(crate) fn html_element_ref<'a, T>(
+    element: &'a web_sys::Element,
+    local_name: &str,
+) -> Option<&'a T>
+where
+    T: JsCast,
+{
+    (element.namespace_uri().as_deref() == Some(XHTML_NAMESPACE)
+        && element.local_name() == local_name)
+        .then(|| element.unchecked_ref())
+}
+
+/// Whether the value is an element that belongs to the realm of a frame.
+pub(crate) fn is_frame_element(value: &JsValue) -> bool {
+    !value.is_instance_of::<web_sys::Node>() && web_sys::Element::is_object(value)
+}
+
+/// Whether an element that belongs to the realm of a frame has the type `T`.
+///
+/// The interface of an element depends on its namespace and local name, so
+/// it's checked against an element of the main window created with them.
+pub(crate) fn frame_element_has_type<T>(element: &web_sys::Element) -> bool
+where
+    T: JsCast,
+{
+    let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+        return false;
+    };
+    document
+        .create_element_ns(element.namespace_uri().as_deref(), &element.local_name())
+        .is_ok_and(|model| model.has_type::<T>())
+}
+
+/// Type of a DOM node.
+fn node_type(value: &JsValue) -> Option<u16> {
+    if !value.is_object() {
+        return None;
+    }
+    js_sys::Reflect::get(value, &JsValue::from_str("nodeType"))
+        .ok()?
+        .as_f64()
+        .map(|node_type| node_type as u16)
+}
+
+/// Class of a platform object, like `Storage`, as given by
+/// `Object.prototype.toString`.
+fn class_name(value: &JsValue) -> Option<String> {
+    if !value.is_object() {
+        return None;
+    }
+    let to_string = js_sys::Reflect::get(&js_sys::Object::new(), &JsValue::from_str("toString"))
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+    let tag = to_string.call0(value).ok()?.as_string()?;
+    tag.strip_prefix("[object ")?
+        .strip_suffix(']')
+        .map(str::to_string)
+}
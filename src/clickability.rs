@@ -0,0 +1,66 @@
+use crate::{cast::cast_ref, visibility::invisibility_reason};
+
+/// Reason why an element can't be clicked by the user, if any.
+///
+/// An element is considered clickable when it is visible, it is not disabled
+/// (neither by its `disabled` attribute nor by an ancestor
+/// `<fieldset disabled>`), it is not inside an `aria-disabled="true"` element,
+/// its computed `pointer-events` is not `none` and it's the topmost element at
+/// its center point.
+pub(crate) fn unclickability_reason(element: &web_sys::Element) -> Option<String> {
+    if let Some(reason) = invisibility_reason(element) {
+        return Some(reason);
+    }
+
+    if element.matches(":disabled").unwrap_or(false) {
+        if element.has_attribute("disabled") {
+            return Some("element is disabled".to_string());
+        }
+        return Some("element is disabled by an ancestor <fieldset disabled>".to_string());
+    }
+    if let Ok(Some(aria_disabled)) = element.closest("[aria-disabled='true']") {
+        if aria_disabled == *element {
+            return Some("element has `aria-disabled=\"true\"`".to_string());
+        }
+        return Some(format!(
+            "ancestor <{}> has `aria-disabled=\"true\"`",
+            aria_disabled.tag_name().to_lowercase()
+        ));
+    }
+
+    let window = web_sys::window()?;
+    if let Ok(Some(style)) = window.get_computed_style(element) {
+        let pointer_events = style
+            .get_property_value("pointer-events")
+            .unwrap_or_default();
+        if pointer_events == "none" {
+            return Some("computed pointer-events is \"none\"".to_string());
+        }
+    }
+
+    let rect = element.get_bounding_client_rect();
+    let (x, y) = (
+        rect.left() + rect.width() / 2.0,
+        rect.top() + rect.height() / 2.0,
+    );
+    // The element is searched in its own tree, because the document would
+    // return the shadow host for elements inside a shadow root.
+    let root = element.get_root_node();
+    let topmost = if let Some(shadow_root) = cast_ref::<web_sys::ShadowRoot>(&root) {
+        shadow_root.element_from_point(x as f32, y as f32)
+    } else if let Some(document) = cast_ref::<web_sys::Document>(&root) {
+        document.element_from_point(x as f32, y as f32)
+    } else {
+        return Some("element is not attached to a document".to_string());
+    };
+    match topmost {
+        Some(topmost) if element.contains(Some(&topmost)) => None,
+        Some(topmost) => Some(format!(
+            "element is covered by <{}> at its center point ({x}, {y})",
+            topmost.tag_name().to_lowercase()
+        )),
+        None => Some(format!(
+            "center point ({x}, {y}) is outside of the viewport"
+        )),
+    }
+}
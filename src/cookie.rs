@@ -0,0 +1,26 @@
+use crate::find::js_error_message;
+use web_sys::wasm_bindgen::JsCast;
+
+/// Value of the cookie with the given name in `document.cookie`, if any.
+///
+/// Returns an error if the cookies of the document can't be read.
+pub(crate) fn cookie_value(
+    document: &web_sys::Document,
+    name: &str,
+) -> Result<Option<String>, String> {
+    // `document.cookie` is defined for every document, also for the
+    // documents of frames that are not instances of the main window types.
+    let cookie = document
+        .unchecked_ref::<web_sys::HtmlDocument>()
+        .cookie()
+        .map_err(|error| {
+            format!(
+                "document.cookie not available: {}",
+                js_error_message(&error)
+            )
+        })?;
+    Ok(cookie.split(';').find_map(|pair| {
+        let (cookie_name, value) = pair.split_once('=').unwrap_or(("", pair));
+        (cookie_name.trim() == name).then(|| value.trim().to_string())
+    }))
+}
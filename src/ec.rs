@@ -1,64 +1,365 @@
 pub(crate) mod inner {
+    use crate::{focus::describe_element, ReadyState, StorageKind};
+
     pub enum Ec {
         InnerTextContains(String),
+        InnerTextIs(String, bool),
+        InnerTextMatches(String),
+        TextContentContains(String),
         AttributeValueIs(String, String),
-        LocalStorageAttributeValueIs(String, String),
+        AttributeValueContains(String, String),
+        AttributeValueStartsWith(String, String),
+        AttributeValueMatches(String, String),
+        HasAttribute(String),
+        LacksAttribute(String),
+        HasClass(Vec<String>),
+        LacksClass(Vec<String>),
+        CssValueIs(String, String),
+        CssValueContains(String, String),
+        CssValueMatches(String, String),
+        ValueIs(String),
+        ValueContains(String),
+        StorageValueIs(StorageKind, String, String),
+        StorageHasKey(StorageKind, String),
+        StorageLacksKey(StorageKind, String),
+        LocalStorageJsonPathIs(String, String, String),
+        CookieValueIs(String, String),
+        CookieExists(String),
+        CookieAbsent(String),
+        TitleIs(String),
+        TitleContains(String),
+        ReadyStateIs(ReadyState),
+        FontsLoaded,
+        StalenessOf(web_sys::Element),
         LocationSearchIs(String),
+        LocationPathnameIs(String),
+        LocationHashIs(String),
+        LocationHrefIs(String),
+        UrlContains(String),
+        UrlMatches(String),
+        SearchParamIs(String, String),
+        And(Box<Ec>, Box<Ec>),
+        Or(Box<Ec>, Box<Ec>),
+        Not(Box<Ec>),
+        Custom(String, Box<dyn Fn(&web_sys::Element) -> bool>),
+        IsVisible,
+        IsHidden,
+        IsClickable,
+        IsChecked,
+        IsSelected,
+        IsEnabled,
+        IsDisabled,
+        HasFocus(bool),
+        ActiveElementMatches(String),
+        CountIs(usize),
+        CountAtLeast(usize),
+        CountAtMost(usize),
     }
 
-    impl core::fmt::Display for Ec {
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    impl Ec {
+        /// First non logical condition of the condition tree.
+        pub(crate) fn first_leaf(&self) -> &Ec {
+            match self {
+                Ec::And(a, _) | Ec::Or(a, _) | Ec::Not(a) => a.first_leaf(),
+                _ => self,
+            }
+        }
+
+        /// Whether the condition can change without DOM mutations, like the
+        /// computed style, the value typed in an input or the focus.
+        pub(crate) fn changes_without_mutations(&self) -> bool {
+            match self {
+                Ec::And(a, b) | Ec::Or(a, b) => {
+                    a.changes_without_mutations() || b.changes_without_mutations()
+                }
+                Ec::Not(a) => a.changes_without_mutations(),
+                Ec::CssValueIs(_, _)
+                | Ec::CssValueContains(_, _)
+                | Ec::CssValueMatches(_, _)
+                | Ec::ValueIs(_)
+                | Ec::ValueContains(_)
+                | Ec::Custom(_, _)
+                | Ec::IsVisible
+                | Ec::IsHidden
+                | Ec::IsClickable
+                | Ec::IsChecked
+                | Ec::IsSelected
+                | Ec::HasFocus(_)
+                | Ec::ActiveElementMatches(_) => true,
+                _ => false,
+            }
+        }
+
+        fn fmt_description(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             match self {
-                Ec::InnerTextContains(text) => write!(
+                Ec::InnerTextContains(text) => {
+                    write!(f, "HTML element innerText contains the text {text:?}")
+                }
+                Ec::InnerTextIs(text, false) => {
+                    write!(f, "HTML element innerText is equal to {text:?}")
+                }
+                Ec::InnerTextIs(text, true) => write!(
+                    f,
+                    "HTML element innerText with normalized whitespace is equal to {text:?}"
+                ),
+                Ec::InnerTextMatches(pattern) => write!(
                     f,
-                    "HTML element innerText contains the text {:?} (`{:?}`)",
-                    text, &self
+                    "HTML element innerText matches the regular expression {pattern:?}"
                 ),
+                Ec::TextContentContains(text) => {
+                    write!(f, "HTML element textContent contains the text {text:?}")
+                }
                 Ec::AttributeValueIs(attr, value) => {
                     write!(
                         f,
-                        "HTML element attribute {:?} value is equal to {:?} (`{:?}`)",
-                        attr, value, &self
+                        "HTML element attribute {attr:?} value is equal to {value:?}"
                     )
                 }
-                Ec::LocalStorageAttributeValueIs(attr, value) => {
+                Ec::AttributeValueContains(attr, value) => {
                     write!(
                         f,
-                        "localStorage attribute {:?} value is equal to {:?} (`{:?}`)",
-                        attr, value, &self
+                        "HTML element attribute {attr:?} value contains {value:?}"
                     )
                 }
-                Ec::LocationSearchIs(value) => {
+                Ec::AttributeValueStartsWith(attr, value) => {
                     write!(
                         f,
-                        "window.location.search is equal to {:?} (`{:?}`)",
-                        value, &self
+                        "HTML element attribute {attr:?} value starts with {value:?}"
                     )
                 }
+                Ec::AttributeValueMatches(attr, pattern) => write!(
+                    f,
+                    "HTML element attribute {attr:?} value matches the regular expression {pattern:?}"
+                ),
+                Ec::HasAttribute(attr) => write!(f, "HTML element has the attribute {attr:?}"),
+                Ec::LacksAttribute(attr) => {
+                    write!(f, "HTML element does not have the attribute {attr:?}")
+                }
+                Ec::HasClass(classes) => match classes.as_slice() {
+                    [class] => write!(f, "HTML element has the class {class:?}"),
+                    _ => write!(f, "HTML element has all the classes {classes:?}"),
+                },
+                Ec::LacksClass(classes) => match classes.as_slice() {
+                    [class] => write!(f, "HTML element does not have the class {class:?}"),
+                    _ => write!(f, "HTML element has none of the classes {classes:?}"),
+                },
+                Ec::CssValueIs(property, value) => write!(
+                    f,
+                    "HTML element computed CSS property {property:?} value is equal to {value:?}"
+                ),
+                Ec::CssValueContains(property, value) => write!(
+                    f,
+                    "HTML element computed CSS property {property:?} value contains {value:?}"
+                ),
+                Ec::CssValueMatches(property, pattern) => write!(
+                    f,
+                    "HTML element computed CSS property {property:?} value matches the regular expression {pattern:?}"
+                ),
+                Ec::ValueIs(value) => write!(f, "form control value is equal to {value:?}"),
+                Ec::ValueContains(value) => write!(f, "form control value contains {value:?}"),
+                Ec::StorageValueIs(kind, attr, value) => {
+                    write!(f, "{kind} attribute {attr:?} value is equal to {value:?}")
+                }
+                Ec::StorageHasKey(kind, key) => write!(f, "{kind} has the key {key:?}"),
+                Ec::StorageLacksKey(kind, key) => {
+                    write!(f, "{kind} does not have the key {key:?}")
+                }
+                Ec::LocalStorageJsonPathIs(key, pointer, expected) => write!(
+                    f,
+                    "localStorage JSON value of key {key:?} at pointer {pointer:?} is equal to {expected}"
+                ),
+                Ec::CookieValueIs(name, value) => {
+                    write!(f, "cookie {name:?} value is equal to {value:?}")
+                }
+                Ec::CookieExists(name) => write!(f, "cookie {name:?} exists"),
+                Ec::CookieAbsent(name) => write!(f, "cookie {name:?} does not exist"),
+                Ec::TitleIs(title) => write!(f, "document.title is equal to {title:?}"),
+                Ec::TitleContains(title) => write!(f, "document.title contains {title:?}"),
+                Ec::ReadyStateIs(state) => {
+                    write!(f, "document.readyState is equal to \"{state}\"")
+                }
+                Ec::FontsLoaded => write!(f, "document.fonts status is \"loaded\""),
+                Ec::StalenessOf(element) => write!(
+                    f,
+                    "HTML element {} is detached from the DOM",
+                    describe_element(element)
+                ),
+                Ec::LocationSearchIs(value) => {
+                    write!(f, "window.location.search is equal to {value:?}")
+                }
+                Ec::LocationPathnameIs(value) => {
+                    write!(f, "window.location.pathname is equal to {value:?}")
+                }
+                Ec::LocationHashIs(value) => {
+                    write!(f, "window.location.hash is equal to {value:?}")
+                }
+                Ec::LocationHrefIs(value) => {
+                    write!(f, "window.location.href is equal to {value:?}")
+                }
+                Ec::UrlContains(value) => write!(f, "window.location.href contains {value:?}"),
+                Ec::UrlMatches(pattern) => write!(
+                    f,
+                    "window.location.href matches the regular expression {pattern:?}"
+                ),
+                Ec::SearchParamIs(key, value) => write!(
+                    f,
+                    "window.location.search parameter {key:?} is equal to {value:?}"
+                ),
+                Ec::Custom(description, _) => write!(f, "{description}"),
+                Ec::IsVisible => write!(f, "HTML element is visible"),
+                Ec::IsHidden => write!(f, "HTML element is hidden"),
+                Ec::IsClickable => write!(f, "HTML element is clickable"),
+                Ec::IsChecked => write!(f, "HTML input is checked"),
+                Ec::IsSelected => write!(f, "HTML option is selected"),
+                Ec::IsEnabled => write!(f, "HTML element is enabled"),
+                Ec::IsDisabled => write!(f, "HTML element is disabled"),
+                Ec::HasFocus(false) => write!(f, "HTML element has focus"),
+                Ec::HasFocus(true) => {
+                    write!(f, "HTML element or one of its descendants has focus")
+                }
+                Ec::ActiveElementMatches(selector) => {
+                    write!(f, "document.activeElement matches the selector {selector:?}")
+                }
+                Ec::CountIs(count) => {
+                    write!(f, "number of matching HTML elements is equal to {count}")
+                }
+                Ec::CountAtLeast(count) => {
+                    write!(f, "number of matching HTML elements is at least {count}")
+                }
+                Ec::CountAtMost(count) => {
+                    write!(f, "number of matching HTML elements is at most {count}")
+                }
+                Ec::And(a, b) => {
+                    write!(f, "(")?;
+                    a.fmt_description(f)?;
+                    write!(f, ") and (")?;
+                    b.fmt_description(f)?;
+                    write!(f, ")")
+                }
+                Ec::Or(a, b) => {
+                    write!(f, "(")?;
+                    a.fmt_description(f)?;
+                    write!(f, ") or (")?;
+                    b.fmt_description(f)?;
+                    write!(f, ")")
+                }
+                Ec::Not(a) => {
+                    write!(f, "not (")?;
+                    a.fmt_description(f)?;
+                    write!(f, ")")
+                }
             }
         }
     }
 
+    impl core::fmt::Display for Ec {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            self.fmt_description(f)?;
+            write!(f, " (`{self:?}`)")
+        }
+    }
+
     impl core::fmt::Debug for Ec {
         fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             match self {
                 Ec::InnerTextContains(text) => write!(f, "Ec::InnerTextContains({text:?})"),
+                Ec::InnerTextIs(text, false) => write!(f, "Ec::InnerTextIs({text:?})"),
+                Ec::InnerTextIs(text, true) => write!(f, "Ec::NormalizedInnerTextIs({text:?})"),
+                Ec::InnerTextMatches(pattern) => write!(f, "Ec::InnerTextMatches({pattern:?})"),
+                Ec::TextContentContains(text) => write!(f, "Ec::TextContentContains({text:?})"),
                 Ec::AttributeValueIs(attr, value) => {
                     write!(f, "Ec::AttributeValueIs({attr:?}, {value:?})")
                 }
-                Ec::LocalStorageAttributeValueIs(attr, value) => {
+                Ec::AttributeValueContains(attr, value) => {
+                    write!(f, "Ec::AttributeValueContains({attr:?}, {value:?})")
+                }
+                Ec::AttributeValueStartsWith(attr, value) => {
+                    write!(f, "Ec::AttributeValueStartsWith({attr:?}, {value:?})")
+                }
+                Ec::AttributeValueMatches(attr, pattern) => {
+                    write!(f, "Ec::AttributeValueMatches({attr:?}, {pattern:?})")
+                }
+                Ec::HasAttribute(attr) => write!(f, "Ec::HasAttribute({attr:?})"),
+                Ec::LacksAttribute(attr) => write!(f, "Ec::LacksAttribute({attr:?})"),
+                Ec::HasClass(classes) => write!(f, "Ec::HasClass({:?})", classes.join(" ")),
+                Ec::LacksClass(classes) => write!(f, "Ec::LacksClass({:?})", classes.join(" ")),
+                Ec::CssValueIs(property, value) => {
+                    write!(f, "Ec::CssValueIs({property:?}, {value:?})")
+                }
+                Ec::CssValueContains(property, value) => {
+                    write!(f, "Ec::CssValueContains({property:?}, {value:?})")
+                }
+                Ec::CssValueMatches(property, pattern) => {
+                    write!(f, "Ec::CssValueMatches({property:?}, {pattern:?})")
+                }
+                Ec::ValueIs(value) => write!(f, "Ec::ValueIs({value:?})"),
+                Ec::ValueContains(value) => write!(f, "Ec::ValueContains({value:?})"),
+                Ec::StorageValueIs(StorageKind::Local, attr, value) => {
                     write!(f, "Ec::LocalStorageAttributeValueIs({attr:?}, {value:?})",)
                 }
+                Ec::StorageValueIs(StorageKind::Session, key, value) => {
+                    write!(f, "Ec::SessionStorageValueIs({key:?}, {value:?})")
+                }
+                Ec::StorageHasKey(kind, key) => {
+                    write!(f, "Ec::StorageHasKey(StorageKind::{kind:?}, {key:?})")
+                }
+                Ec::StorageLacksKey(kind, key) => {
+                    write!(f, "Ec::StorageLacksKey(StorageKind::{kind:?}, {key:?})")
+                }
+                Ec::LocalStorageJsonPathIs(key, pointer, expected) => write!(
+                    f,
+                    "Ec::LocalStorageJsonPathIs({key:?}, {pointer:?}, {expected:?})"
+                ),
+                Ec::CookieValueIs(name, value) => {
+                    write!(f, "Ec::CookieValueIs({name:?}, {value:?})")
+                }
+                Ec::CookieExists(name) => write!(f, "Ec::CookieExists({name:?})"),
+                Ec::CookieAbsent(name) => write!(f, "Ec::CookieAbsent({name:?})"),
+                Ec::TitleIs(title) => write!(f, "Ec::TitleIs({title:?})"),
+                Ec::TitleContains(title) => write!(f, "Ec::TitleContains({title:?})"),
+                Ec::ReadyStateIs(state) => write!(f, "Ec::ReadyStateIs(ReadyState::{state:?})"),
+                Ec::FontsLoaded => write!(f, "Ec::FontsLoaded"),
+                Ec::StalenessOf(element) => {
+                    write!(f, "Ec::StalenessOf({})", describe_element(element))
+                }
                 Ec::LocationSearchIs(value) => write!(f, "Ec::LocationSearchIs({value:?})"),
+                Ec::LocationPathnameIs(value) => write!(f, "Ec::LocationPathnameIs({value:?})"),
+                Ec::LocationHashIs(value) => write!(f, "Ec::LocationHashIs({value:?})"),
+                Ec::LocationHrefIs(value) => write!(f, "Ec::LocationHrefIs({value:?})"),
+                Ec::UrlContains(value) => write!(f, "Ec::UrlContains({value:?})"),
+                Ec::UrlMatches(pattern) => write!(f, "Ec::UrlMatches({pattern:?})"),
+                Ec::SearchParamIs(key, value) => {
+                    write!(f, "Ec::SearchParamIs({key:?}, {value:?})")
+                }
+                Ec::And(a, b) => write!(f, "Ec::And({a:?}, {b:?})"),
+                Ec::Or(a, b) => write!(f, "Ec::Or({a:?}, {b:?})"),
+                Ec::Not(a) => write!(f, "Ec::Not({a:?})"),
+                Ec::Custom(description, _) => write!(f, "Ec::Custom({description:?})"),
+                Ec::IsVisible => write!(f, "Ec::IsVisible"),
+                Ec::IsHidden => write!(f, "Ec::IsHidden"),
+                Ec::IsClickable => write!(f, "Ec::IsClickable"),
+                Ec::IsChecked => write!(f, "Ec::IsChecked"),
+                Ec::IsSelected => write!(f, "Ec::IsSelected"),
+                Ec::IsEnabled => write!(f, "Ec::IsEnabled"),
+                Ec::IsDisabled => write!(f, "Ec::IsDisabled"),
+                Ec::HasFocus(false) => write!(f, "Ec::HasFocus"),
+                Ec::HasFocus(true) => write!(f, "Ec::HasFocusWithin"),
+                Ec::ActiveElementMatches(selector) => {
+                    write!(f, "Ec::ActiveElementMatches({selector:?})")
+                }
+                Ec::CountIs(count) => write!(f, "Ec::CountIs({count})"),
+                Ec::CountAtLeast(count) => write!(f, "Ec::CountAtLeast({count})"),
+                Ec::CountAtMost(count) => write!(f, "Ec::CountAtMost({count})"),
             }
         }
     }
 }
 
 /// Conditions to be expected while waiting.
-#[allow(non_snake_case)]
+#[allow(non_snake_case, non_upper_case_globals)]
 pub mod Ec {
     use super::inner;
+    use crate::{ReadyState, StorageKind};
 
     /// The property `innerText` of an element contains the given text.
     ///
@@ -72,6 +373,64 @@ pub mod Ec {
         inner::Ec::InnerTextContains(text.into())
     }
 
+    /// The property `innerText` of an element is equal to the given text.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("p", Ec::InnerTextIs("text")));
+    /// ```
+    #[inline]
+    pub fn InnerTextIs(text: impl Into<String>) -> inner::Ec {
+        inner::Ec::InnerTextIs(text.into(), false)
+    }
+
+    /// The property `innerText` of an element is equal to the given text
+    /// after normalizing whitespace.
+    ///
+    /// Leading and trailing whitespace is removed and every other sequence of
+    /// whitespace characters is replaced by a single space in both texts.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("p", Ec::NormalizedInnerTextIs("some text")));
+    /// ```
+    #[inline]
+    pub fn NormalizedInnerTextIs(text: impl Into<String>) -> inner::Ec {
+        inner::Ec::InnerTextIs(text.into(), true)
+    }
+
+    /// The property `innerText` of an element matches the given regular
+    /// expression.
+    ///
+    /// The pattern uses the JavaScript regular expressions syntax.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("p", Ec::InnerTextMatches(r"^\d+ items?$")));
+    /// ```
+    #[inline]
+    pub fn InnerTextMatches(pattern: impl Into<String>) -> inner::Ec {
+        inner::Ec::InnerTextMatches(pattern.into())
+    }
+
+    /// The property `textContent` of an element contains the given text.
+    ///
+    /// Unlike `innerText`, `textContent` includes the text of hidden elements
+    /// and it's available for non HTML elements like SVG `<text>`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("svg text", Ec::TextContentContains("text")));
+    /// ```
+    #[inline]
+    pub fn TextContentContains(text: impl Into<String>) -> inner::Ec {
+        inner::Ec::TextContentContains(text.into())
+    }
+
     /// The attribute value of an element is equal to the given value.
     ///
     /// ```rust,ignore
@@ -84,6 +443,175 @@ pub mod Ec {
         inner::Ec::AttributeValueIs(attr.into(), value.into())
     }
 
+    /// The attribute value of an element contains the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("div", Ec::AttributeValueContains("style", "color")));
+    /// ```
+    #[inline]
+    pub fn AttributeValueContains(attr: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::AttributeValueContains(attr.into(), value.into())
+    }
+
+    /// The attribute value of an element starts with the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("a", Ec::AttributeValueStartsWith("href", "/es/")));
+    /// ```
+    #[inline]
+    pub fn AttributeValueStartsWith(
+        attr: impl Into<String>,
+        value: impl Into<String>,
+    ) -> inner::Ec {
+        inner::Ec::AttributeValueStartsWith(attr.into(), value.into())
+    }
+
+    /// The attribute value of an element matches the given regular expression.
+    ///
+    /// The pattern uses the JavaScript regular expressions syntax.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("a", Ec::AttributeValueMatches("href", r"^/\w+/docs/")));
+    /// ```
+    #[inline]
+    pub fn AttributeValueMatches(attr: impl Into<String>, pattern: impl Into<String>) -> inner::Ec {
+        inner::Ec::AttributeValueMatches(attr.into(), pattern.into())
+    }
+
+    /// An element has the given attribute, whatever its value is.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("ul", Ec::HasAttribute("aria-busy")));
+    /// ```
+    #[inline]
+    pub fn HasAttribute(attr: impl Into<String>) -> inner::Ec {
+        inner::Ec::HasAttribute(attr.into())
+    }
+
+    /// An element does not have the given attribute.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("ul", Ec::LacksAttribute("aria-busy")));
+    /// ```
+    #[inline]
+    pub fn LacksAttribute(attr: impl Into<String>) -> inner::Ec {
+        inner::Ec::LacksAttribute(attr.into())
+    }
+
+    /// An element has the given class.
+    ///
+    /// Pass several classes separated by whitespace to require all of them.
+    /// The order of the classes in the element doesn't matter.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#tab", Ec::HasClass("is-active")));
+    /// Wait(1).until(("#form", Ec::HasClass("error shake")));
+    /// ```
+    #[inline]
+    pub fn HasClass(classes: impl Into<String>) -> inner::Ec {
+        inner::Ec::HasClass(split_classes(&classes.into()))
+    }
+
+    /// An element does not have the given class.
+    ///
+    /// Pass several classes separated by whitespace to require none of them
+    /// to be present.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#list", Ec::LacksClass("loading")));
+    /// ```
+    #[inline]
+    pub fn LacksClass(classes: impl Into<String>) -> inner::Ec {
+        inner::Ec::LacksClass(split_classes(&classes.into()))
+    }
+
+    /// The computed value of a CSS property of an element is equal to the
+    /// given value.
+    ///
+    /// The value is read with `window.getComputedStyle(element)`, so it's
+    /// serialized by the browser, like `rgb(255, 0, 0)` for colors.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#toast", Ec::CssValueIs("opacity", "1")));
+    /// ```
+    #[inline]
+    pub fn CssValueIs(property: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::CssValueIs(property.into(), value.into())
+    }
+
+    /// The computed value of a CSS property of an element contains the given
+    /// value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#card", Ec::CssValueContains("font-family", "Inter")));
+    /// ```
+    #[inline]
+    pub fn CssValueContains(property: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::CssValueContains(property.into(), value.into())
+    }
+
+    /// The computed value of a CSS property of an element matches the given
+    /// regular expression.
+    ///
+    /// The pattern uses the JavaScript regular expressions syntax.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#panel", Ec::CssValueMatches("height", r"^[1-9]\d*px$")));
+    /// ```
+    #[inline]
+    pub fn CssValueMatches(property: impl Into<String>, pattern: impl Into<String>) -> inner::Ec {
+        inner::Ec::CssValueMatches(property.into(), pattern.into())
+    }
+
+    /// The current value of an `<input>`, `<textarea>` or `<select>` is
+    /// equal to the given value.
+    ///
+    /// Unlike `AttributeValueIs("value", ...)`, it reads the `value` property,
+    /// which reflects what the user has typed.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#email", Ec::ValueIs("user@example.com")));
+    /// ```
+    #[inline]
+    pub fn ValueIs(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::ValueIs(value.into())
+    }
+
+    /// The current value of an `<input>`, `<textarea>` or `<select>`
+    /// contains the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("textarea", Ec::ValueContains("Regards")));
+    /// ```
+    #[inline]
+    pub fn ValueContains(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::ValueContains(value.into())
+    }
+
     /// The localStorage attribute value is equal to the given value.
     ///
     /// ```rust,ignore
@@ -96,7 +624,159 @@ pub mod Ec {
         attr: impl Into<String>,
         value: impl Into<String>,
     ) -> inner::Ec {
-        inner::Ec::LocalStorageAttributeValueIs(attr.into(), value.into())
+        inner::Ec::StorageValueIs(StorageKind::Local, attr.into(), value.into())
+    }
+
+    /// The sessionStorage value of a key is equal to the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::SessionStorageValueIs("token", "abc"));
+    /// ```
+    #[inline]
+    pub fn SessionStorageValueIs(key: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::StorageValueIs(StorageKind::Session, key.into(), value.into())
+    }
+
+    /// The given storage has the key, whatever its value is.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, StorageKind, Wait};
+    ///
+    /// Wait(1).until(Ec::StorageHasKey(StorageKind::Session, "token"));
+    /// ```
+    #[inline]
+    pub fn StorageHasKey(kind: StorageKind, key: impl Into<String>) -> inner::Ec {
+        inner::Ec::StorageHasKey(kind, key.into())
+    }
+
+    /// The given storage does not have the key.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, StorageKind, Wait};
+    ///
+    /// Wait(1).until(Ec::StorageLacksKey(StorageKind::Local, "cart"));
+    /// ```
+    #[inline]
+    pub fn StorageLacksKey(kind: StorageKind, key: impl Into<String>) -> inner::Ec {
+        inner::Ec::StorageLacksKey(kind, key.into())
+    }
+
+    /// The localStorage value of a key, parsed as JSON, has the expected JSON
+    /// value at the given [JSON Pointer].
+    ///
+    /// Values are compared structurally, so the order of the keys of objects
+    /// and the whitespace don't matter. An empty pointer refers to the whole
+    /// value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::LocalStorageJsonPathIs("state", "/user/theme", r#""dark""#));
+    /// Wait(1).until(Ec::LocalStorageJsonPathIs("state", "/cart", "[]"));
+    /// ```
+    ///
+    /// [JSON Pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+    #[inline]
+    pub fn LocalStorageJsonPathIs(
+        key: impl Into<String>,
+        pointer: impl Into<String>,
+        expected: impl Into<String>,
+    ) -> inner::Ec {
+        inner::Ec::LocalStorageJsonPathIs(key.into(), pointer.into(), expected.into())
+    }
+
+    /// The value of the cookie with the given name in `document.cookie` is
+    /// equal to the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::CookieValueIs("consent", "accepted"));
+    /// ```
+    #[inline]
+    pub fn CookieValueIs(name: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::CookieValueIs(name.into(), value.into())
+    }
+
+    /// A cookie with the given name exists in `document.cookie`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::CookieExists("session"));
+    /// ```
+    #[inline]
+    pub fn CookieExists(name: impl Into<String>) -> inner::Ec {
+        inner::Ec::CookieExists(name.into())
+    }
+
+    /// No cookie with the given name exists in `document.cookie`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::CookieAbsent("session"));
+    /// ```
+    #[inline]
+    pub fn CookieAbsent(name: impl Into<String>) -> inner::Ec {
+        inner::Ec::CookieAbsent(name.into())
+    }
+
+    /// The `document.title` is equal to the given title.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::TitleIs("Settings | My app"));
+    /// ```
+    #[inline]
+    pub fn TitleIs(title: impl Into<String>) -> inner::Ec {
+        inner::Ec::TitleIs(title.into())
+    }
+
+    /// The `document.title` contains the given text.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::TitleContains("Settings"));
+    /// ```
+    #[inline]
+    pub fn TitleContains(title: impl Into<String>) -> inner::Ec {
+        inner::Ec::TitleContains(title.into())
+    }
+
+    /// The `document.readyState` is equal to the given state.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, ReadyState, Wait};
+    ///
+    /// Wait(1).until(Ec::ReadyStateIs(ReadyState::Complete));
+    /// ```
+    #[inline]
+    pub fn ReadyStateIs(state: ReadyState) -> inner::Ec {
+        inner::Ec::ReadyStateIs(state)
+    }
+
+    /// The given element is no longer attached to the DOM.
+    ///
+    /// Unlike `Wait::until_not` with a selector, which passes as soon as no
+    /// element matches it, this waits for a concrete element to be removed,
+    /// even if another element matching the same selector replaces it.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// let row = Wait(1).until_element("#row-1").await;
+    /// // ... trigger a re-render
+    /// Wait(1).until(Ec::StalenessOf(row)).await;
+    /// Wait(1).until("#row-1").await;
+    /// ```
+    #[inline]
+    pub fn StalenessOf(element: web_sys::Element) -> inner::Ec {
+        inner::Ec::StalenessOf(element)
     }
 
     /// The `window.location.search` is equal to the given value.
@@ -110,4 +790,346 @@ pub mod Ec {
     pub fn LocationSearchIs(value: impl Into<String>) -> inner::Ec {
         inner::Ec::LocationSearchIs(value.into())
     }
+
+    /// The `window.location.pathname` is equal to the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::LocationPathnameIs("/es/settings"));
+    /// ```
+    #[inline]
+    pub fn LocationPathnameIs(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::LocationPathnameIs(value.into())
+    }
+
+    /// The `window.location.hash` is equal to the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::LocationHashIs("#section-2"));
+    /// ```
+    #[inline]
+    pub fn LocationHashIs(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::LocationHashIs(value.into())
+    }
+
+    /// The `window.location.href` is equal to the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::LocationHrefIs("https://example.com/es/"));
+    /// ```
+    #[inline]
+    pub fn LocationHrefIs(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::LocationHrefIs(value.into())
+    }
+
+    /// The `window.location.href` contains the given value.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::UrlContains("/settings"));
+    /// ```
+    #[inline]
+    pub fn UrlContains(value: impl Into<String>) -> inner::Ec {
+        inner::Ec::UrlContains(value.into())
+    }
+
+    /// The `window.location.href` matches the given regular expression.
+    ///
+    /// The pattern uses the JavaScript regular expressions syntax.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::UrlMatches(r"/users/\d+$"));
+    /// ```
+    #[inline]
+    pub fn UrlMatches(pattern: impl Into<String>) -> inner::Ec {
+        inner::Ec::UrlMatches(pattern.into())
+    }
+
+    /// The query parameter `key` of `window.location.search` is equal to the
+    /// given value.
+    ///
+    /// The query is parsed with `URLSearchParams`, so the order of the
+    /// parameters and their encoding don't matter. If the parameter is
+    /// repeated, its first value is compared.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::SearchParamIs("page", "2"));
+    /// ```
+    #[inline]
+    pub fn SearchParamIs(key: impl Into<String>, value: impl Into<String>) -> inner::Ec {
+        inner::Ec::SearchParamIs(key.into(), value.into())
+    }
+
+    /// Both conditions are met.
+    ///
+    /// Both conditions are checked against the same element in each attempt.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until((
+    ///     "button",
+    ///     Ec::And(
+    ///         Ec::AttributeValueIs("aria-busy", "false"),
+    ///         Ec::InnerTextContains("Save"),
+    ///     ),
+    /// ));
+    /// ```
+    #[inline]
+    pub fn And(a: inner::Ec, b: inner::Ec) -> inner::Ec {
+        inner::Ec::And(Box::new(a), Box::new(b))
+    }
+
+    /// At least one of the conditions is met.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until((
+    ///     "html",
+    ///     Ec::Or(
+    ///         Ec::AttributeValueIs("lang", "es"),
+    ///         Ec::AttributeValueIs("lang", "en"),
+    ///     ),
+    /// ));
+    /// ```
+    #[inline]
+    pub fn Or(a: inner::Ec, b: inner::Ec) -> inner::Ec {
+        inner::Ec::Or(Box::new(a), Box::new(b))
+    }
+
+    /// The condition is not met.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("p", Ec::Not(Ec::InnerTextContains("Loading"))));
+    /// ```
+    #[inline]
+    pub fn Not(ec: inner::Ec) -> inner::Ec {
+        inner::Ec::Not(Box::new(ec))
+    }
+
+    /// The given predicate returns `true` for the element.
+    ///
+    /// The description is used to show the condition in error messages.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until((
+    ///     "ul",
+    ///     Ec::Custom("list has children", |element| element.child_element_count() > 0),
+    /// ));
+    /// ```
+    #[inline]
+    pub fn Custom(
+        description: impl Into<String>,
+        predicate: impl Fn(&web_sys::Element) -> bool + 'static,
+    ) -> inner::Ec {
+        inner::Ec::Custom(description.into(), Box::new(predicate))
+    }
+
+    /// The element is visible.
+    ///
+    /// Neither the element nor any of its ancestors have the `hidden`
+    /// attribute, its computed `display` is not `none`, its computed
+    /// `visibility` is not `hidden`, its computed `opacity` is greater than
+    /// zero and its bounding client rect has a non zero size.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#dialog", Ec::IsVisible));
+    /// ```
+    pub const IsVisible: inner::Ec = inner::Ec::IsVisible;
+
+    /// The element exists but is not visible.
+    ///
+    /// See [`IsVisible`] for the conditions checked. To wait for an element
+    /// to be removed from the DOM use `Wait::until_not` with its selector.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#dialog", Ec::IsHidden));
+    /// ```
+    pub const IsHidden: inner::Ec = inner::Ec::IsHidden;
+
+    /// The element can be clicked.
+    ///
+    /// The element is visible (see [`IsVisible`]), it is not disabled neither
+    /// by its `disabled` attribute nor by an ancestor `<fieldset disabled>`,
+    /// it is not inside an `aria-disabled="true"` element, its computed
+    /// `pointer-events` is not `none` and it's the topmost element at its
+    /// center point according to `document.elementFromPoint`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("button", Ec::IsClickable));
+    /// ```
+    pub const IsClickable: inner::Ec = inner::Ec::IsClickable;
+
+    /// The checkbox or radio `<input>` is checked.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#accept-terms", Ec::IsChecked));
+    /// ```
+    pub const IsChecked: inner::Ec = inner::Ec::IsChecked;
+
+    /// The `<option>` is selected.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#language option[value='es']", Ec::IsSelected));
+    /// ```
+    pub const IsSelected: inner::Ec = inner::Ec::IsSelected;
+
+    /// The element is enabled, so it doesn't match the `:disabled`
+    /// pseudo-class.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("button[type='submit']", Ec::IsEnabled));
+    /// ```
+    pub const IsEnabled: inner::Ec = inner::Ec::IsEnabled;
+
+    /// The element is disabled, either by its `disabled` attribute or by an
+    /// ancestor `<fieldset disabled>`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("button[type='submit']", Ec::IsDisabled));
+    /// ```
+    pub const IsDisabled: inner::Ec = inner::Ec::IsDisabled;
+
+    /// The element is the focused element of its document, or of its shadow
+    /// root if it's inside one.
+    ///
+    /// A shadow host doesn't have focus when the focus is inside its shadow
+    /// root. Use [`HasFocusWithin`] to accept it.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#dialog input[name='email']", Ec::HasFocus));
+    /// ```
+    pub const HasFocus: inner::Ec = inner::Ec::HasFocus(false);
+
+    /// The element or one of its descendants has focus, including elements
+    /// inside the shadow roots of its descendants, like the CSS
+    /// `:focus-within` pseudo-class.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("#dialog", Ec::HasFocusWithin));
+    /// ```
+    pub const HasFocusWithin: inner::Ec = inner::Ec::HasFocus(true);
+
+    /// The `document.activeElement` matches the given CSS selector.
+    ///
+    /// When the focus is inside a shadow root, the active element of the
+    /// document is its host.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::ActiveElementMatches("#dialog button.close"));
+    /// ```
+    #[inline]
+    pub fn ActiveElementMatches(selector: impl Into<String>) -> inner::Ec {
+        inner::Ec::ActiveElementMatches(selector.into())
+    }
+
+    /// All the fonts of the document have finished loading, so the
+    /// `document.fonts.status` is `"loaded"`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(Ec::FontsLoaded);
+    /// ```
+    pub const FontsLoaded: inner::Ec = inner::Ec::FontsLoaded;
+
+    /// The number of elements matching the selector is equal to the given count.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("table tr", Ec::CountIs(10)));
+    /// Wait(1).until((".toast", Ec::CountIs(0)));
+    /// ```
+    #[inline]
+    pub fn CountIs(count: usize) -> inner::Ec {
+        inner::Ec::CountIs(count)
+    }
+
+    /// The number of elements matching the selector is at least the given count.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until(("table tr", Ec::CountAtLeast(1)));
+    /// ```
+    #[inline]
+    pub fn CountAtLeast(count: usize) -> inner::Ec {
+        inner::Ec::CountAtLeast(count)
+    }
+
+    /// The number of elements matching the selector is at most the given count.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// Wait(1).until((".toast", Ec::CountAtMost(3)));
+    /// ```
+    #[inline]
+    pub fn CountAtMost(count: usize) -> inner::Ec {
+        inner::Ec::CountAtMost(count)
+    }
+
+    /// Classes of a whitespace separated class list.
+    fn split_classes(classes: &str) -> Vec<String> {
+        classes.split_whitespace().map(String::from).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_condition_tree() {
+        let ec = Ec::And(
+            Ec::AttributeValueIs("aria-busy", "false"),
+            Ec::Not(Ec::InnerTextContains("Loading")),
+        );
+
+        assert_eq!(
+            ec.to_string(),
+            concat!(
+                r#"(HTML element attribute "aria-busy" value is equal to "false")"#,
+                r#" and (not (HTML element innerText contains the text "Loading"))"#,
+                r#" (`Ec::And(Ec::AttributeValueIs("aria-busy", "false"),"#,
+                r#" Ec::Not(Ec::InnerTextContains("Loading")))`)"#,
+            )
+        );
+    }
 }
@@ -0,0 +1,112 @@
+use crate::{by::inner::By, ec::inner::Ec};
+use std::time::Duration;
+
+/// Error returned when an expected condition has not been met in time.
+///
+/// It's also returned without waiting when the condition can't be checked,
+/// like with an invalid XPath expression or regular expression. Use
+/// [`WaitError::is_timeout`] to tell both cases apart.
+///
+/// Returned by `Wait::try_until` and `Wait::try_until_not`. Its `Display`
+/// implementation renders the same message that `Wait::until` panics with.
+#[derive(Debug)]
+pub struct WaitError {
+    pub(crate) frames: Vec<By>,
+    pub(crate) by: Option<By>,
+    pub(crate) ec: Option<Ec>,
+    pub(crate) duration: Duration,
+    pub(crate) poll_frecuency: Duration,
+    pub(crate) number_of_attempts: u32,
+    pub(crate) last_value: Option<String>,
+    pub(crate) invalid_condition: Option<String>,
+    #[cfg(feature = "nightly")]
+    pub(crate) caller_location: std::panic::Location<'static>,
+}
+
+impl WaitError {
+    /// Selectors of the frames in which the condition was checked, from the
+    /// outermost to the innermost.
+    pub fn frames(&self) -> &[By] {
+        &self.frames
+    }
+
+    /// Selector used to find the element, if any.
+    pub fn selector(&self) -> Option<&By> {
+        self.by.as_ref()
+    }
+
+    /// Expected condition that has not been met, if any.
+    pub fn condition(&self) -> Option<&Ec> {
+        self.ec.as_ref()
+    }
+
+    /// Total time waited.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Time between attempts.
+    pub fn poll_frecuency(&self) -> Duration {
+        self.poll_frecuency
+    }
+
+    /// Whether the condition has not been met in time, as opposed to not
+    /// being valid.
+    pub fn is_timeout(&self) -> bool {
+        self.invalid_condition.is_none()
+    }
+
+    /// Reason why the condition can't be checked, if it's not valid.
+    pub fn invalid_condition(&self) -> Option<&str> {
+        self.invalid_condition.as_deref()
+    }
+
+    /// Number of times the condition has been checked.
+    pub fn number_of_attempts(&self) -> u32 {
+        self.number_of_attempts
+    }
+
+    /// Value observed in the last attempt, if any.
+    pub fn last_value(&self) -> Option<&str> {
+        self.last_value.as_deref()
+    }
+
+    /// Location of the code that started the wait.
+    #[cfg(feature = "nightly")]
+    pub fn caller_location(&self) -> &std::panic::Location<'static> {
+        &self.caller_location
+    }
+}
+
+impl core::fmt::Display for WaitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.invalid_condition.is_some() {
+            writeln!(f, "Expected condition can't be checked:")?;
+        } else {
+            writeln!(f, "Expected condition has not been met in the given time:")?;
+        }
+        #[cfg(feature = "nightly")]
+        writeln!(f, "  - Caller: {}", self.caller_location)?;
+        for frame in &self.frames {
+            writeln!(f, "  - Frame: {frame}")?;
+        }
+        if let Some(ref by) = self.by {
+            writeln!(f, "  - Selector: {by}")?;
+        }
+        if let Some(ref ec) = self.ec {
+            writeln!(f, "  - Condition: {ec}")?;
+        }
+        if let Some(ref reason) = self.invalid_condition {
+            return writeln!(f, "  - Reason: {reason}");
+        }
+        writeln!(f, "  - Duration: {:?}", self.duration)?;
+        writeln!(f, "  - Poll frecuency: {:?}", self.poll_frecuency)?;
+        writeln!(f, "  - Number of attempts: {}", self.number_of_attempts)?;
+        if let Some(ref last_value) = self.last_value {
+            writeln!(f, "  - Last value: {last_value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WaitError {}
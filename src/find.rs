@@ -0,0 +1,325 @@
+use crate::{
+    by::inner::By,
+    cast::{cast, html_element_ref},
+};
+use web_sys::wasm_bindgen::JsValue;
+
+/// Node from which elements are searched.
+pub(crate) enum Root {
+    Document(web_sys::Document),
+    Element(web_sys::Element),
+    ShadowRoot(web_sys::ShadowRoot),
+}
+
+/// Reason why an element could not be found.
+pub(crate) enum FindError {
+    /// The selector is not valid, so waiting longer will not help.
+    Invalid(String),
+    /// The element is not reachable or can't be checked for now.
+    Unreachable(String),
+}
+
+impl Root {
+    fn node(&self) -> &web_sys::Node {
+        match self {
+            Root::Document(document) => document,
+            Root::Element(element) => element,
+            Root::ShadowRoot(shadow_root) => shadow_root,
+        }
+    }
+
+    fn document(&self) -> Option<web_sys::Document> {
+        match self {
+            Root::Document(document) => Some(document.clone()),
+            Root::Element(element) => element.owner_document(),
+            Root::ShadowRoot(shadow_root) => shadow_root.owner_document(),
+        }
+    }
+
+    fn get_element_by_id(&self, id: &str) -> Option<web_sys::Element> {
+        match self {
+            Root::Document(document) => document.get_element_by_id(id),
+            Root::Element(element) => element.query_selector(&id_selector(id)).ok().flatten(),
+            Root::ShadowRoot(shadow_root) => shadow_root.get_element_by_id(id),
+        }
+    }
+
+    fn element_by_class_name(&self, class: &str) -> Option<web_sys::Element> {
+        match self {
+            Root::Document(document) => document.get_elements_by_class_name(class).item(0),
+            Root::Element(element) => element.get_elements_by_class_name(class).item(0),
+            Root::ShadowRoot(_) => self.query_selector(&class_selector(class)).ok().flatten(),
+        }
+    }
+
+    fn elements_by_class_name(&self, class: &str) -> Vec<web_sys::Element> {
+        match self {
+            Root::Document(document) => {
+                collection_elements(&document.get_elements_by_class_name(class))
+            }
+            Root::Element(element) => {
+                collection_elements(&element.get_elements_by_class_name(class))
+            }
+            Root::ShadowRoot(_) => self
+                .query_selector_all(&class_selector(class))
+                .map(|nodes| node_list_elements(&nodes))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn element_by_tag_name(&self, tag_name: &str) -> Option<web_sys::Element> {
+        match self {
+            Root::Document(document) => document.get_elements_by_tag_name(tag_name).item(0),
+            Root::Element(element) => element.get_elements_by_tag_name(tag_name).item(0),
+            Root::ShadowRoot(_) => self.query_selector(tag_name).ok().flatten(),
+        }
+    }
+
+    fn elements_by_tag_name(&self, tag_name: &str) -> Vec<web_sys::Element> {
+        match self {
+            Root::Document(document) => {
+                collection_elements(&document.get_elements_by_tag_name(tag_name))
+            }
+            Root::Element(element) => {
+                collection_elements(&element.get_elements_by_tag_name(tag_name))
+            }
+            Root::ShadowRoot(_) => self
+                .query_selector_all(tag_name)
+                .map(|nodes| node_list_elements(&nodes))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn query_selector(&self, selector: &str) -> Result<Option<web_sys::Element>, JsValue> {
+        match self {
+            Root::Document(document) => document.query_selector(selector),
+            Root::Element(element) => element.query_selector(selector),
+            Root::ShadowRoot(shadow_root) => shadow_root.query_selector(selector),
+        }
+    }
+
+    fn query_selector_all(&self, selector: &str) -> Result<web_sys::NodeList, JsValue> {
+        match self {
+            Root::Document(document) => document.query_selector_all(selector),
+            Root::Element(element) => element.query_selector_all(selector),
+            Root::ShadowRoot(shadow_root) => shadow_root.query_selector_all(selector),
+        }
+    }
+
+    fn evaluate(&self, expression: &str, type_: u16) -> Result<web_sys::XPathResult, FindError> {
+        if !matches!(self, Root::Document(_)) && is_absolute_xpath(expression) {
+            return Err(FindError::Invalid(format!(
+                "XPath expression {expression:?} is absolute so it would search the whole document, use a relative expression like \".//button\" inside other elements"
+            )));
+        }
+        let Some(document) = self.document() else {
+            return Err(FindError::Unreachable(
+                "the root node has no owner document".to_string(),
+            ));
+        };
+        document
+            .evaluate_with_opt_callback_and_type(expression, self.node(), None, type_)
+            .map_err(|error| {
+                FindError::Invalid(format!(
+                    "invalid XPath expression {expression:?}: {}",
+                    js_error_message(&error)
+                ))
+            })
+    }
+
+    /// All the `<a>` elements under the root.
+    fn links(&self) -> impl Iterator<Item = web_sys::Element> {
+        self.elements_by_tag_name("a").into_iter()
+    }
+}
+
+/// Find the first element matching the selector under the root.
+///
+/// Returns an error if the selector is not valid or if an element of the
+/// chain can't be reached.
+pub(crate) fn find_element(by: &By, root: &Root) -> Result<Option<web_sys::Element>, FindError> {
+    match by {
+        By::Id(id) => Ok(root.get_element_by_id(id)),
+        By::Class(class) => Ok(root.element_by_class_name(class)),
+        By::TagName(tag_name) => Ok(root.element_by_tag_name(tag_name)),
+        By::QuerySelector(selector) => root
+            .query_selector(selector)
+            .map_err(|error| invalid_selector(selector, &error)),
+        By::XPath(expression) => {
+            let node = root
+                .evaluate(expression, web_sys::XPathResult::FIRST_ORDERED_NODE_TYPE)?
+                .single_node_value()
+                .map_err(|error| FindError::Invalid(js_error_message(&error)))?;
+            match node {
+                Some(node) => match cast(node.clone()) {
+                    Some(element) => Ok(Some(element)),
+                    None => Err(FindError::Invalid(format!(
+                        "XPath expression {expression:?} matches the node {:?} which is not an element",
+                        node.node_name()
+                    ))),
+                },
+                None => Ok(None),
+            }
+        }
+        By::LinkText(text) => Ok(root.links().find(|link| link_text(link) == *text)),
+        By::PartialLinkText(text) => Ok(root.links().find(|link| link_text(link).contains(text))),
+        By::Within(parent, child) => match find_element(parent, root)? {
+            Some(parent) => find_element(child, &Root::Element(parent)),
+            None => Ok(None),
+        },
+        By::Shadow(host, inner) => match find_element(host, root)? {
+            Some(host) => find_element(inner, &shadow_root(&host)?),
+            None => Ok(None),
+        },
+    }
+}
+
+/// Find all the elements matching the selector under the root.
+///
+/// Returns an error if the selector is not valid or if an element of the
+/// chain can't be reached.
+pub(crate) fn find_elements(by: &By, root: &Root) -> Result<Vec<web_sys::Element>, FindError> {
+    match by {
+        By::Id(id) => Ok(root
+            .query_selector_all(&id_selector(id))
+            .map(|nodes| node_list_elements(&nodes))
+            .unwrap_or_default()),
+        By::Class(class) => Ok(root.elements_by_class_name(class)),
+        By::TagName(tag_name) => Ok(root.elements_by_tag_name(tag_name)),
+        By::QuerySelector(selector) => root
+            .query_selector_all(selector)
+            .map(|nodes| node_list_elements(&nodes))
+            .map_err(|error| invalid_selector(selector, &error)),
+        By::XPath(expression) => {
+            let result =
+                root.evaluate(expression, web_sys::XPathResult::ORDERED_NODE_SNAPSHOT_TYPE)?;
+            let length = result.snapshot_length().unwrap_or(0);
+            Ok((0..length)
+                .filter_map(|i| cast(result.snapshot_item(i).ok()??))
+                .collect())
+        }
+        By::LinkText(text) => Ok(root
+            .links()
+            .filter(|link| link_text(link) == *text)
+            .collect()),
+        By::PartialLinkText(text) => Ok(root
+            .links()
+            .filter(|link| link_text(link).contains(text))
+            .collect()),
+        By::Within(parent, child) => match find_element(parent, root)? {
+            Some(parent) => find_elements(child, &Root::Element(parent)),
+            None => Ok(Vec::new()),
+        },
+        By::Shadow(host, inner) => match find_element(host, root)? {
+            Some(host) => find_elements(inner, &shadow_root(&host)?),
+            None => Ok(Vec::new()),
+        },
+    }
+}
+
+/// Window of a same-origin frame whose document has been completely loaded.
+pub(crate) fn frame_window(frame: &web_sys::Element) -> Result<web_sys::Window, FindError> {
+    let Some(frame) = html_element_ref::<web_sys::HtmlIFrameElement>(frame, "iframe") else {
+        return Err(FindError::Invalid(format!(
+            "frame element <{}> is not an <iframe>",
+            frame.tag_name().to_lowercase()
+        )));
+    };
+    let Some(document) = frame.content_document() else {
+        return Err(FindError::Unreachable(
+            "frame document is not accessible, only same-origin frames are supported".to_string(),
+        ));
+    };
+    // Until the source of the frame is loaded, its content document is an
+    // initial `about:blank` document that can be complete too.
+    let loading_source = document.url().is_ok_and(|url| url == "about:blank")
+        && (frame.has_attribute("srcdoc")
+            || frame
+                .get_attribute("src")
+                .is_some_and(|src| !src.is_empty() && src != "about:blank"));
+    if loading_source || document.ready_state() != "complete" {
+        return Err(FindError::Unreachable(
+            "frame document is still loading".to_string(),
+        ));
+    }
+    frame
+        .content_window()
+        .ok_or_else(|| FindError::Unreachable("frame has no window".to_string()))
+}
+
+/// Open shadow root of a host element.
+fn shadow_root(host: &web_sys::Element) -> Result<Root, FindError> {
+    match host.shadow_root() {
+        Some(shadow_root) => Ok(Root::ShadowRoot(shadow_root)),
+        None => Err(FindError::Unreachable(format!(
+            "shadow host <{}> has no open shadow root, closed shadow roots are not reachable",
+            host.tag_name().to_lowercase()
+        ))),
+    }
+}
+
+fn collection_elements(collection: &web_sys::HtmlCollection) -> Vec<web_sys::Element> {
+    (0..collection.length())
+        .filter_map(|i| collection.item(i))
+        .collect()
+}
+
+fn node_list_elements(nodes: &web_sys::NodeList) -> Vec<web_sys::Element> {
+    (0..nodes.length())
+        .filter_map(|i| cast(nodes.item(i)?))
+        .collect()
+}
+
+/// Trimmed `innerText` of a link.
+fn link_text(link: &web_sys::Element) -> String {
+    html_element_ref::<web_sys::HtmlElement>(link, "a")
+        .map(|link| link.inner_text().trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Whether an XPath expression starts from the root of the document, like
+/// `//button` or `(//button)[1]`, instead of from the context node.
+fn is_absolute_xpath(expression: &str) -> bool {
+    expression
+        .trim_start()
+        .trim_start_matches(|c: char| c == '(' || c.is_whitespace())
+        .starts_with('/')
+}
+
+/// Escape a value to be used inside a double quoted CSS string.
+fn escape_css_string(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// CSS selector matching elements with the given id.
+fn id_selector(id: &str) -> String {
+    format!("[id=\"{}\"]", escape_css_string(id))
+}
+
+/// CSS selector matching elements with the given class.
+fn class_selector(class: &str) -> String {
+    format!("[class~=\"{}\"]", escape_css_string(class))
+}
+
+fn invalid_selector(selector: &str, error: &JsValue) -> FindError {
+    FindError::Invalid(format!(
+        "invalid selector {selector:?}: {}",
+        js_error_message(error)
+    ))
+}
+
+/// Message of a JavaScript error.
+///
+/// The message is read structurally because errors thrown inside a frame
+/// are not instances of the `Error` constructor of the main window.
+pub(crate) fn js_error_message(error: &JsValue) -> String {
+    let message = error
+        .is_object()
+        .then(|| js_sys::Reflect::get(error, &JsValue::from_str("message")).ok())
+        .flatten()
+        .and_then(|message| message.as_string());
+    match message {
+        Some(message) => message,
+        None => format!("{error:?}"),
+    }
+}
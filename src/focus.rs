@@ -0,0 +1,37 @@
+use crate::cast::cast_ref;
+
+/// Focused element in the document or shadow root that contains the element.
+///
+/// When the focus is inside a shadow root, the active element of the tree is
+/// its host.
+pub(crate) fn active_element(element: &web_sys::Element) -> Option<web_sys::Element> {
+    let root = element.get_root_node();
+    if let Some(shadow_root) = cast_ref::<web_sys::ShadowRoot>(&root) {
+        return shadow_root.active_element();
+    }
+    element.owner_document()?.active_element()
+}
+
+/// Focused element in the tree that contains the element, going down through
+/// the active elements of open shadow roots to the one actually focused.
+pub(crate) fn deepest_active_element(element: &web_sys::Element) -> Option<web_sys::Element> {
+    let mut active = active_element(element)?;
+    while let Some(inner) = active
+        .shadow_root()
+        .and_then(|shadow_root| shadow_root.active_element())
+    {
+        active = inner;
+    }
+    Some(active)
+}
+
+/// Short description of an element, like `<button id="save">`.
+pub(crate) fn describe_element(element: &web_sys::Element) -> String {
+    let tag_name = element.tag_name().to_lowercase();
+    let id = element.id();
+    if id.is_empty() {
+        format!("<{tag_name}>")
+    } else {
+        format!("<{tag_name} id={id:?}>")
+    }
+}
@@ -0,0 +1,50 @@
+use crate::cast::html_element_ref;
+
+/// Current value of a form control.
+///
+/// Returns an error if the element is not an `<input>`, `<textarea>` or
+/// `<select>`.
+pub(crate) fn form_control_value(element: &web_sys::Element) -> Result<String, String> {
+    if let Some(input) = html_element_ref::<web_sys::HtmlInputElement>(element, "input") {
+        Ok(input.value())
+    } else if let Some(textarea) =
+        html_element_ref::<web_sys::HtmlTextAreaElement>(element, "textarea")
+    {
+        Ok(textarea.value())
+    } else if let Some(select) = html_element_ref::<web_sys::HtmlSelectElement>(element, "select") {
+        Ok(select.value())
+    } else {
+        Err(format!(
+            "<{}> element has no value",
+            element.tag_name().to_lowercase()
+        ))
+    }
+}
+
+/// Whether a checkbox or radio `<input>` is checked.
+///
+/// Returns an error if the element is not an `<input>`.
+pub(crate) fn is_checked(element: &web_sys::Element) -> Result<bool, String> {
+    html_element_ref::<web_sys::HtmlInputElement>(element, "input")
+        .map(web_sys::HtmlInputElement::checked)
+        .ok_or_else(|| {
+            format!(
+                "<{}> element can't be checked",
+                element.tag_name().to_lowercase()
+            )
+        })
+}
+
+/// Whether an `<option>` is selected.
+///
+/// Returns an error if the element is not an `<option>`.
+pub(crate) fn is_selected(element: &web_sys::Element) -> Result<bool, String> {
+    html_element_ref::<web_sys::HtmlOptionElement>(element, "option")
+        .map(web_sys::HtmlOptionElement::selected)
+        .ok_or_else(|| {
+            format!(
+                "<{}> element can't be selected",
+                element.tag_name().to_lowercase()
+            )
+        })
+}
@@ -0,0 +1,103 @@
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// Reference tokens of a JSON Pointer, as defined in RFC 6901.
+///
+/// Returns an error if the pointer is not empty and doesn't start with `/`.
+pub(crate) fn pointer_tokens(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(tokens) = pointer.strip_prefix('/') else {
+        return Err(format!(
+            "invalid JSON pointer {pointer:?}: it must be empty or start with '/'"
+        ));
+    };
+    Ok(tokens
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+/// Value referenced by the tokens of a JSON Pointer, if any.
+pub(crate) fn resolve_pointer(value: &JsValue, tokens: &[String]) -> Option<JsValue> {
+    let mut value = value.clone();
+    for token in tokens {
+        value = if let Some(array) = value.dyn_ref::<js_sys::Array>() {
+            if token != "0" && token.starts_with('0') {
+                return None;
+            }
+            let index = token.parse::<u32>().ok()?;
+            (index < array.length()).then(|| array.get(index))?
+        } else if is_plain_object(&value) {
+            let key = JsValue::from_str(token);
+            if !value
+                .unchecked_ref::<js_sys::Object>()
+                .has_own_property(&key)
+            {
+                return None;
+            }
+            js_sys::Reflect::get(&value, &key).ok()?
+        } else {
+            return None;
+        };
+    }
+    Some(value)
+}
+
+/// Structural equality of two parsed JSON values.
+///
+/// The order of the keys of objects doesn't matter.
+pub(crate) fn json_eq(a: &JsValue, b: &JsValue) -> bool {
+    match (a.dyn_ref::<js_sys::Array>(), b.dyn_ref::<js_sys::Array>()) {
+        (Some(a), Some(b)) => {
+            return a.length() == b.length()
+                && (0..a.length()).all(|index| json_eq(&a.get(index), &b.get(index)));
+        }
+        (None, None) => {}
+        _ => return false,
+    }
+    if is_plain_object(a) && is_plain_object(b) {
+        let a_keys = js_sys::Object::keys(a.unchecked_ref());
+        let b = b.unchecked_ref::<js_sys::Object>();
+        return a_keys.length() == js_sys::Object::keys(b).length()
+            && a_keys.iter().all(|key| {
+                b.has_own_property(&key)
+                    && match (js_sys::Reflect::get(a, &key), js_sys::Reflect::get(b, &key)) {
+                        (Ok(a), Ok(b)) => json_eq(&a, &b),
+                        _ => false,
+                    }
+            });
+    }
+    a == b
+}
+
+/// Serialize a parsed JSON value back to text.
+pub(crate) fn stringify(value: &JsValue) -> String {
+    js_sys::JSON::stringify(value)
+        .map(String::from)
+        .unwrap_or_default()
+}
+
+fn is_plain_object(value: &JsValue) -> bool {
+    value.is_object() && !js_sys::Array::is_array(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_pointer_tokens() {
+        assert_eq!(pointer_tokens(""), Ok(vec![]));
+        assert_eq!(
+            pointer_tokens("/user/settings~1theme/0/a~0b"),
+            Ok(vec![
+                "user".to_string(),
+                "settings/theme".to_string(),
+                "0".to_string(),
+                "a~b".to_string(),
+            ])
+        );
+        assert!(pointer_tokens("user").is_err());
+    }
+}
@@ -83,6 +83,26 @@
 //! Wait(0.2).until("p#foo").await;
 //! ```
 //!
+//! Wait 1 second for the same element, but checking the condition only when the
+//! DOM changes instead of polling:
+//!
+//! ```rust,ignore
+//! use web_sys_ec::{Wait, WaitStrategy};
+//!
+//! Wait(1)
+//!     .with_strategy(WaitStrategy::MutationObserver)
+//!     .until("p#foo")
+//!     .await;
+//! ```
+//!
+//! Wait 1 second for a `<p id="foo">` HTML element to exist and return it:
+//!
+//! ```rust,ignore
+//! use web_sys_ec::{By, Wait};
+//!
+//! let element = Wait(1).until_element(By::Id("foo")).await;
+//! ```
+//!
 //! If a condition is not met, it will panic with a message like:
 //!
 //! <!-- markdownlint-disable MD013 -->
@@ -102,6 +122,17 @@
 //! Note that the `Caller: ...` line will only be shown if you're using the `nightly`
 //! toolchain and the `nightly` feature is enabled.
 //!
+//! Use `try_until` and `try_until_not` to get a `WaitError` instead of panicking:
+//!
+//! ```rust,ignore
+//! use web_sys_ec::{By, Wait};
+//!
+//! if let Err(err) = Wait(1).try_until(By::Id("foo")).await {
+//!     // fall back to another element
+//!     Wait(1).until(By::Id("bar")).await;
+//! }
+//! ```
+//!
 //! # Features
 //!
 //! - `nightly`: Enables nightly toolchain support, which is currently needed to
@@ -110,17 +141,32 @@
 //! [`web-sys`]: https://crates.io/crates/web-sys
 
 pub(crate) mod by;
+mod cast;
+mod clickability;
 mod condition;
+mod cookie;
 pub(crate) mod ec;
+mod error;
+mod find;
+mod focus;
+mod form;
+mod json;
+mod ready_state;
+mod regexp;
+mod storage;
 mod until;
+mod visibility;
 mod wait;
 mod wait_options;
 
 pub use by::By;
 pub(crate) use condition::Condition;
 pub use ec::Ec;
-pub(crate) use until::{until_impl, until_not_impl};
+pub use error::WaitError;
+pub use ready_state::ReadyState;
+pub use storage::StorageKind;
+pub(crate) use until::{single_element_error, until_impl, until_not_impl};
 pub use wait::Wait;
 #[doc(hidden)]
 pub(crate) use wait::Wait as Waiter;
-pub use wait_options::WaitOptions;
+pub use wait_options::{WaitOptions, WaitStrategy};
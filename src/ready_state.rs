@@ -0,0 +1,28 @@
+/// Loading state of a document, as reported by `document.readyState`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadyState {
+    /// The document is still loading.
+    Loading,
+    /// The document has been parsed but subresources like images, stylesheets
+    /// and frames are still loading.
+    Interactive,
+    /// The document and all its subresources have finished loading.
+    Complete,
+}
+
+impl ReadyState {
+    /// Value of `document.readyState` for this state.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            ReadyState::Loading => "loading",
+            ReadyState::Interactive => "interactive",
+            ReadyState::Complete => "complete",
+        }
+    }
+}
+
+impl core::fmt::Display for ReadyState {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,21 @@
+use crate::find::js_error_message;
+use web_sys::wasm_bindgen::{JsCast, JsValue};
+
+/// Compile a JavaScript regular expression.
+///
+/// Returns an error if the pattern is not valid.
+pub(crate) fn new_regexp(pattern: &str) -> Result<js_sys::RegExp, String> {
+    let constructor = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("RegExp"))
+        .map_err(|error| js_error_message(&error))?;
+    js_sys::Reflect::construct(
+        constructor.unchecked_ref::<js_sys::Function>(),
+        &js_sys::Array::of1(&JsValue::from_str(pattern)),
+    )
+    .map(JsCast::unchecked_into::<js_sys::RegExp>)
+    .map_err(|error| {
+        format!(
+            "invalid regular expression {pattern:?}: {}",
+            js_error_message(&error)
+        )
+    })
+}
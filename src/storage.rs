@@ -0,0 +1,27 @@
+/// Web storage in which storage conditions are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageKind {
+    /// The `window.localStorage`, persisted across browser sessions.
+    Local,
+    /// The `window.sessionStorage`, cleared when the page session ends.
+    Session,
+}
+
+impl StorageKind {
+    /// Storage of this kind in the given window, if available.
+    pub(crate) fn storage(self, window: &web_sys::Window) -> Option<web_sys::Storage> {
+        match self {
+            StorageKind::Local => window.local_storage().ok()?,
+            StorageKind::Session => window.session_storage().ok()?,
+        }
+    }
+}
+
+impl core::fmt::Display for StorageKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StorageKind::Local => write!(f, "localStorage"),
+            StorageKind::Session => write!(f, "sessionStorage"),
+        }
+    }
+}
@@ -1,12 +1,25 @@
-use crate::{by::inner::By, ec::inner::Ec, Condition, Waiter as Wait};
-use std::boxed::Box;
-use web_sys::wasm_bindgen::JsCast;
+use crate::{
+    cast::{cast, Object},
+    clickability::unclickability_reason,
+    cookie::cookie_value,
+    ec::inner::Ec,
+    find::{find_element, find_elements, frame_window, js_error_message, FindError, Root},
+    focus::{active_element, deepest_active_element, describe_element},
+    form::{form_control_value, is_checked, is_selected},
+    json::{json_eq, pointer_tokens, resolve_pointer, stringify},
+    regexp::new_regexp,
+    storage::StorageKind,
+    visibility::invisibility_reason,
+    Condition, WaitError, WaitStrategy, Waiter as Wait,
+};
+use std::{boxed::Box, cell::RefCell, rc::Rc, time::Duration};
+use web_sys::wasm_bindgen::{closure::Closure, JsCast, JsValue};
 
 pub(crate) async fn until_impl(
     condition: Condition,
     wait: Wait,
     #[cfg(feature = "nightly")] caller_location: &std::panic::Location<'static>,
-) {
+) -> Result<Option<JsValue>, WaitError> {
     Conditioner {
         condition,
         wait,
@@ -15,14 +28,14 @@ pub(crate) async fn until_impl(
         caller_location: *caller_location,
     }
     .resolve()
-    .await;
+    .await
 }
 
 pub(crate) async fn until_not_impl(
     condition: Condition,
     wait: Wait,
     #[cfg(feature = "nightly")] caller_location: &std::panic::Location<'static>,
-) {
+) -> Result<Option<JsValue>, WaitError> {
     Conditioner {
         condition,
         wait,
@@ -31,7 +44,202 @@ pub(crate) async fn until_not_impl(
         caller_location: *caller_location,
     }
     .resolve()
-    .await;
+    .await
+}
+
+/// Reason why a condition doesn't match a single element, if any.
+pub(crate) fn single_element_error(condition: &Condition) -> Option<String> {
+    if condition.by.is_none() {
+        return Some("conditions without a `By` selector don't match an element".to_string());
+    }
+    let ec = condition.ec.as_ref()?;
+    match Target::of(ec) {
+        Ok(Target::Elements) => Some(format!(
+            "{ec} is checked over all the elements matched by the selector"
+        )),
+        _ => None,
+    }
+}
+
+/// Outcome of checking an expected condition against an object.
+struct Check {
+    matched: bool,
+    value: Option<String>,
+}
+
+impl Check {
+    fn new(matched: bool, value: impl Into<String>) -> Self {
+        Self {
+            matched,
+            value: Some(value.into()),
+        }
+    }
+}
+
+/// Object over which an expected condition is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Node,
+    Element,
+    HtmlElement,
+    Elements,
+    Storage(StorageKind),
+    Location,
+    Document,
+    /// The `document.activeElement`.
+    ActiveElement,
+    /// The element given to `Ec::StalenessOf`.
+    StaleElement,
+}
+
+impl Target {
+    /// Object over which an expected condition is checked.
+    ///
+    /// Returns an error if the condition combines conditions that are checked
+    /// over different objects.
+    fn of(ec: &Ec) -> Result<Self, String> {
+        Ok(match ec {
+            Ec::InnerTextContains(_) | Ec::InnerTextIs(_, _) | Ec::InnerTextMatches(_) => {
+                Self::HtmlElement
+            }
+            Ec::TextContentContains(_) => Self::Element,
+            Ec::AttributeValueIs(_, _)
+            | Ec::AttributeValueContains(_, _)
+            | Ec::AttributeValueStartsWith(_, _)
+            | Ec::AttributeValueMatches(_, _)
+            | Ec::HasAttribute(_)
+            | Ec::LacksAttribute(_)
+            | Ec::HasClass(_)
+            | Ec::LacksClass(_)
+            | Ec::CssValueIs(_, _)
+            | Ec::CssValueContains(_, _)
+            | Ec::CssValueMatches(_, _)
+            | Ec::ValueIs(_)
+            | Ec::ValueContains(_)
+            | Ec::IsChecked
+            | Ec::IsSelected
+            | Ec::IsEnabled
+            | Ec::IsDisabled
+            | Ec::HasFocus(_) => Self::Element,
+            Ec::ActiveElementMatches(_) => Self::ActiveElement,
+            Ec::StorageValueIs(kind, _, _)
+            | Ec::StorageHasKey(kind, _)
+            | Ec::StorageLacksKey(kind, _) => Self::Storage(*kind),
+            Ec::LocalStorageJsonPathIs(_, _, _) => Self::Storage(StorageKind::Local),
+            Ec::CookieValueIs(_, _)
+            | Ec::CookieExists(_)
+            | Ec::CookieAbsent(_)
+            | Ec::TitleIs(_)
+            | Ec::TitleContains(_)
+            | Ec::ReadyStateIs(_)
+            | Ec::FontsLoaded => Self::Document,
+            Ec::StalenessOf(_) => Self::StaleElement,
+            Ec::LocationSearchIs(_)
+            | Ec::LocationPathnameIs(_)
+            | Ec::LocationHashIs(_)
+            | Ec::LocationHrefIs(_)
+            | Ec::UrlContains(_)
+            | Ec::UrlMatches(_)
+            | Ec::SearchParamIs(_, _) => Self::Location,
+            Ec::Custom(_, _) | Ec::IsVisible | Ec::IsHidden | Ec::IsClickable => Self::Element,
+            Ec::CountIs(_) | Ec::CountAtLeast(_) | Ec::CountAtMost(_) => Self::Elements,
+            Ec::And(a, b) | Ec::Or(a, b) => match (a.first_leaf(), b.first_leaf()) {
+                (Ec::StalenessOf(a_element), Ec::StalenessOf(b_element))
+                    if a_element != b_element =>
+                {
+                    return Err(format!(
+                        "conditions combined in {ec:?} are checked over different elements so they can't be combined"
+                    ))
+                }
+                _ => Self::of(a)?.join(Self::of(b)?, ec)?,
+            },
+            Ec::Not(a) => Self::of(a)?,
+        })
+    }
+
+    /// Most specific object type that satisfies both targets.
+    fn join(self, other: Self, ec: &Ec) -> Result<Self, String> {
+        match (self, other) {
+            (a, b) if a == b => Ok(a),
+            (Self::Node | Self::Element, b @ (Self::Element | Self::HtmlElement)) => Ok(b),
+            (a @ (Self::Element | Self::HtmlElement), Self::Node | Self::Element) => Ok(a),
+            _ => Err(format!(
+                "conditions combined in {ec:?} are checked over different objects so they can't be combined"
+            )),
+        }
+    }
+}
+
+/// Reason why a wait has failed.
+enum Failure {
+    /// The condition has not been met in time.
+    Timeout {
+        number_of_attempts: u32,
+        last_value: Option<String>,
+    },
+    /// The condition can't be checked, so waiting longer will not help.
+    InvalidCondition(String),
+}
+
+/// Observes the whole document to notify about changes in the DOM.
+struct DomObserver {
+    observer: web_sys::MutationObserver,
+    resolve: Rc<RefCell<Option<js_sys::Function>>>,
+    _callback: Closure<dyn FnMut()>,
+}
+
+impl DomObserver {
+    fn new() -> Option<Self> {
+        let document = web_sys::window()?.document()?;
+        let resolve = Rc::new(RefCell::new(None::<js_sys::Function>));
+        let callback = {
+            let resolve = Rc::clone(&resolve);
+            Closure::<dyn FnMut()>::new(move || {
+                if let Some(resolve) = resolve.borrow_mut().take() {
+                    _ = resolve.call0(&JsValue::UNDEFINED);
+                }
+            })
+        };
+        let observer = web_sys::MutationObserver::new(callback.as_ref().unchecked_ref()).ok()?;
+        let options = web_sys::MutationObserverInit::new();
+        options.set_subtree(true);
+        options.set_attributes(true);
+        options.set_character_data(true);
+        options.set_child_list(true);
+        observer.observe_with_options(&document, &options).ok()?;
+        Some(Self {
+            observer,
+            resolve,
+            _callback: callback,
+        })
+    }
+
+    /// Wait until the DOM changes or the timeout expires.
+    async fn changed(&self, timeout: Duration) {
+        let Some(window) = web_sys::window() else {
+            return;
+        };
+        let mut timeout_handle = None;
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            timeout_handle = window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(
+                    &resolve,
+                    timeout.as_millis().try_into().unwrap_or(i32::MAX),
+                )
+                .ok();
+            *self.resolve.borrow_mut() = Some(resolve);
+        });
+        _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+        if let Some(handle) = timeout_handle {
+            window.clear_timeout_with_handle(handle);
+        }
+    }
+}
+
+impl Drop for DomObserver {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
 }
 
 #[derive(Debug)]
@@ -44,240 +252,757 @@ pub(crate) struct Conditioner {
 }
 
 impl Conditioner {
-    pub(crate) async fn resolve(&self) {
-        match self.condition.ec {
+    pub(crate) async fn resolve(self) -> Result<Option<JsValue>, WaitError> {
+        let target = match self.condition.ec {
             None => match self.condition.by.as_ref() {
                 None => {
                     // TODO: better error message
                     panic!("Expected condition is not set");
                 }
-                Some(_) => self.wait_for_object::<web_sys::Node>().await,
+                Some(_) => Ok(Target::Node),
             },
-            Some(Ec::InnerTextContains(_)) => self.wait_for_object::<web_sys::HtmlElement>().await,
-            Some(Ec::AttributeValueIs(_, _)) => self.wait_for_object::<web_sys::Element>().await,
-            Some(Ec::LocalStorageAttributeValueIs(_, _)) => {
-                self.wait_for_object::<web_sys::Storage>().await;
+            Some(ref ec) => Target::of(ec),
+        };
+        let validation = target.and_then(|target| {
+            if let Some(ref ec) = self.condition.ec {
+                match (self.condition.by.is_some(), uses_selector(ec)?) {
+                    (false, true) => {
+                        return Err(format!("expected condition {ec} requires a selector"))
+                    }
+                    (true, false) => {
+                        return Err(format!("expected condition {ec} doesn't take a selector"))
+                    }
+                    _ => {}
+                }
+                validate(ec)?;
             }
-            Some(Ec::LocationSearchIs(_)) => self.wait_for_object::<web_sys::Location>().await,
+            Ok(target)
+        });
+        let result = match validation {
+            Err(error) => Err(Failure::InvalidCondition(error)),
+            Ok(target) => match target {
+                Target::Node => self.wait_for_object::<web_sys::Node>(target).await,
+                Target::Element => self.wait_for_object::<web_sys::Element>(target).await,
+                Target::HtmlElement => self.wait_for_object::<web_sys::HtmlElement>(target).await,
+                Target::Elements => self.wait_for_object::<js_sys::Array>(target).await,
+                Target::Storage(_) => self.wait_for_object::<web_sys::Storage>(target).await,
+                Target::Location => self.wait_for_object::<web_sys::Location>(target).await,
+                Target::Document => self.wait_for_object::<web_sys::Document>(target).await,
+                Target::ActiveElement => self.wait_for_object::<web_sys::Element>(target).await,
+                Target::StaleElement => self.wait_for_object::<web_sys::Element>(target).await,
+            },
+        };
+
+        result.map_err(|failure| {
+            let wait_options = &self.wait.options;
+            let (number_of_attempts, last_value, invalid_condition) = match failure {
+                Failure::Timeout {
+                    number_of_attempts,
+                    last_value,
+                } => (number_of_attempts, last_value, None),
+                Failure::InvalidCondition(reason) => (0, None, Some(reason)),
+            };
+            WaitError {
+                frames: self.wait.frames,
+                by: self.condition.by,
+                ec: self.condition.ec,
+                duration: wait_options.duration(),
+                poll_frecuency: wait_options.poll_frecuency(),
+                number_of_attempts,
+                last_value,
+                invalid_condition,
+                #[cfg(feature = "nightly")]
+                caller_location: self.caller_location,
+            }
+        })
+    }
+
+    /// Window in which the condition is checked, found through the frames of
+    /// the wait.
+    fn window(&self) -> Result<Option<web_sys::Window>, FindError> {
+        let Some(mut window) = web_sys::window() else {
+            return Ok(None);
+        };
+        for frame in &self.wait.frames {
+            let Some(document) = window.document() else {
+                return Ok(None);
+            };
+            let Some(element) = find_element(frame, &Root::Document(document))? else {
+                return Err(FindError::Unreachable(format!("no frame found: {frame}")));
+            };
+            window = frame_window(&element)?;
         }
+        Ok(Some(window))
     }
 
-    pub(crate) async fn wait_for_object<T>(&self)
+    async fn wait_for_object<T>(&self, target: Target) -> Result<Option<JsValue>, Failure>
     where
-        T: 'static + web_sys::wasm_bindgen::JsCast,
+        T: 'static + Object,
     {
-        let waiter_fn: Box<dyn Fn() -> Option<T>> = match self.condition.by.as_ref() {
-            None => Box::new(move || match self.condition.ec {
-                Some(Ec::LocalStorageAttributeValueIs(_, _)) => {
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(Some(local_storage)) = window.local_storage() {
-                            if let Ok(storage) = local_storage.dyn_into::<T>() {
-                                Some(storage)
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                Some(Ec::LocationSearchIs(_)) => {
-                    if let Some(window) = web_sys::window() {
-                        if let Ok(location) = window.location().dyn_into::<T>() {
-                            Some(location)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                _ => unreachable!(),
-            }),
-            Some(By::Id(id)) => Box::new(move || {
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        if let Some(element) = document.get_element_by_id(&id.to_string()) {
-                            if let Ok(element) = element.dyn_into::<T>() {
-                                Some(element)
-                            } else {
-                                // TODO: here panic, doesn't casts to expected minimum type
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
+        let waiter_fn: Box<dyn Fn() -> Result<Option<T>, FindError>> = match self.condition.by {
+            None => Box::new(move || {
+                let Some(window) = self.window()? else {
+                    return Ok(None);
+                };
+                Ok(match self.condition.ec.as_ref().map(Ec::first_leaf) {
+                    Some(
+                        Ec::StorageValueIs(kind, _, _)
+                        | Ec::StorageHasKey(kind, _)
+                        | Ec::StorageLacksKey(kind, _),
+                    ) => kind.storage(&window).and_then(cast),
+                    Some(Ec::LocalStorageJsonPathIs(_, _, _)) => {
+                        StorageKind::Local.storage(&window).and_then(cast)
                     }
-                } else {
-                    None
-                }
-            }),
-            Some(By::Class(class)) => Box::new(move || {
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        let maybe_element = document
-                            .get_elements_by_class_name(&class.to_string())
-                            .item(0);
-                        if let Some(element) = maybe_element {
-                            if let Ok(element) = element.dyn_into::<T>() {
-                                Some(element)
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+                    Some(
+                        Ec::CookieValueIs(_, _)
+                        | Ec::CookieExists(_)
+                        | Ec::CookieAbsent(_)
+                        | Ec::TitleIs(_)
+                        | Ec::TitleContains(_)
+                        | Ec::ReadyStateIs(_)
+                        | Ec::FontsLoaded,
+                    ) => window.document().and_then(cast),
+                    Some(Ec::StalenessOf(element)) => cast(element.clone()),
+                    Some(
+                        Ec::LocationSearchIs(_)
+                        | Ec::LocationPathnameIs(_)
+                        | Ec::LocationHashIs(_)
+                        | Ec::LocationHrefIs(_)
+                        | Ec::UrlContains(_)
+                        | Ec::UrlMatches(_)
+                        | Ec::SearchParamIs(_, _),
+                    ) => cast(window.location()),
+                    Some(Ec::ActiveElementMatches(_)) => window
+                        .document()
+                        .and_then(|document| document.active_element())
+                        .and_then(cast),
+                    Some(_) | None => unreachable!(),
+                })
             }),
-            Some(By::TagName(tag_name)) => Box::new(move || {
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        let maybe_element = document
-                            .get_elements_by_tag_name(&tag_name.to_string())
-                            .item(0);
-                        if let Some(element) = maybe_element {
-                            if let Ok(element) = element.dyn_into::<T>() {
-                                Some(element)
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+            Some(ref by) if target == Target::Elements => Box::new(move || {
+                let Some(document) = self.window()?.and_then(|window| window.document()) else {
+                    return Ok(None);
+                };
+                let elements = find_elements(by, &Root::Document(document))?;
+                Ok(cast(elements.into_iter().collect::<js_sys::Array>()))
             }),
-            Some(By::QuerySelector(selector)) => Box::new(move || {
-                if let Some(window) = web_sys::window() {
-                    if let Some(document) = window.document() {
-                        let maybe_element = document.query_selector(&selector.to_string());
-                        if let Ok(Some(element)) = maybe_element {
-                            if let Ok(element) = element.dyn_into::<T>() {
-                                Some(element)
-                            } else {
-                                None
-                            }
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    None
+            Some(ref by) => Box::new(move || {
+                let Some(document) = self.window()?.and_then(|window| window.document()) else {
+                    return Ok(None);
+                };
+                match find_element(by, &Root::Document(document))? {
+                    Some(element) => match cast(element.clone()) {
+                        Some(object) => Ok(Some(object)),
+                        None => Err(FindError::Unreachable(format!(
+                            "element {} is not an HTML element",
+                            describe_element(&element)
+                        ))),
+                    },
+                    None => Ok(None),
                 }
             }),
         };
 
-        let ec_fn: Box<dyn Fn(&T) -> bool> = match self.condition.ec {
-            None => Box::new(move |_| true),
-            Some(Ec::InnerTextContains(ref text)) => Box::new(move |element: &T| {
-                let element = element.unchecked_ref::<web_sys::HtmlElement>();
-                let inner_text = element.inner_text();
-                inner_text.contains(text)
-            }),
-            Some(Ec::AttributeValueIs(ref attribute, ref value)) => Box::new(move |element: &T| {
-                let element = element.unchecked_ref::<web_sys::Element>();
-                let attribute_value = element.get_attribute(attribute);
-                if let Some(attribute_value) = attribute_value {
-                    attribute_value == *value
-                } else {
-                    false
-                }
-            }),
-            Some(Ec::LocalStorageAttributeValueIs(ref attribute, ref value)) => {
-                Box::new(move |storage: &T| {
-                    let storage = storage.unchecked_ref::<web_sys::Storage>();
-                    let attribute_value = storage.get_item(attribute);
-                    if let Ok(Some(attribute_value)) = attribute_value {
-                        attribute_value == *value
-                    } else {
-                        false
-                    }
-                })
-            }
-            Some(Ec::LocationSearchIs(ref value)) => Box::new(move |location: &T| {
-                let location = location.unchecked_ref::<web_sys::Location>();
-                if let Ok(search) = location.search() {
-                    search == *value
-                } else {
-                    false
-                }
+        let ec_fn: Box<dyn Fn(&T) -> Check> = match self.condition.ec {
+            None => Box::new(move |_| Check {
+                matched: true,
+                value: None,
             }),
+            Some(ref ec) => ec_fn(ec),
         };
 
         let wait_options = &self.wait.options;
         let duration = wait_options.duration();
         let poll_frecuency = wait_options.poll_frecuency();
+        let dom_observer = match (wait_options.strategy(), &self.condition.by) {
+            (WaitStrategy::MutationObserver, Some(by))
+                if self.wait.frames.is_empty()
+                    && !by.crosses_shadow_boundary()
+                    && !self
+                        .condition
+                        .ec
+                        .as_ref()
+                        .is_some_and(Ec::changes_without_mutations) =>
+            {
+                DomObserver::new()
+            }
+            _ => None,
+        };
 
         let mut number_of_attempts = 1;
+        let mut last_value = None;
         let start = js_sys::Date::now();
-        while js_sys::Date::now() - start < duration.as_millis() as f64 {
-            if let Some(ref element) = waiter_fn() {
-                let expected_condition_match = ec_fn(element);
-                if (self.negative_until && !expected_condition_match)
-                    || (!self.negative_until && expected_condition_match)
-                {
-                    return;
+        loop {
+            let mut not_found_reason = None;
+            let maybe_element = match waiter_fn() {
+                Ok(maybe_element) => maybe_element,
+                Err(FindError::Invalid(error)) => return Err(Failure::InvalidCondition(error)),
+                Err(FindError::Unreachable(reason)) => {
+                    not_found_reason = Some(reason);
+                    None
+                }
+            };
+            if let Some(element) = maybe_element {
+                let check = ec_fn(&element);
+                if self.negative_until != check.matched {
+                    return Ok((!self.negative_until).then(|| element.into()));
                 }
+                last_value = check.value;
+            } else if not_found_reason.is_some() {
+                // An unreachable frame or shadow root could still contain a
+                // matching element, and an element that can't be checked
+                // could still be replaced, so keep waiting in both modes
+                last_value = not_found_reason;
             } else if self.negative_until {
-                return;
+                return Ok(None);
+            } else if self.condition.by.is_some() {
+                last_value = Some("no element found".to_string());
+            }
+
+            let elapsed = js_sys::Date::now() - start;
+            if elapsed >= duration.as_millis() as f64 {
+                break;
             }
             number_of_attempts += 1;
-            gloo_timers::future::sleep(poll_frecuency).await;
+            if let Some(ref dom_observer) = dom_observer {
+                let remaining = duration.saturating_sub(Duration::from_millis(elapsed as u64));
+                dom_observer.changed(remaining).await;
+            } else {
+                gloo_timers::future::sleep(poll_frecuency).await;
+            }
         }
 
-        // TODO:
-        //   - If there is a `By` selector and the until is positive,
-        //     try to match the element with the selector and print it
-        //     in the panic message either it is found or not.
-        //   - If there is a `By` selector and the until is negative,
-        //     try to match the element with the selector and print it
-        //     in the panic message either it is found or not.
-        panic!(
-            concat!(
-                "\n",
-                "Expected condition has not been met in the given time:\n",
-                "{}",
-                "{}",
-                "  - Duration: {:?}\n",
-                "  - Poll frecuency: {:?}\n",
-                "  - Number of attempts: {}\n",
-            ),
-            {
-                #[cfg(feature = "nightly")]
-                let caller = format!("  - Caller: {}\n", self.caller_location);
-                #[cfg(not(feature = "nightly"))]
-                let caller = String::new();
-                caller
-            },
-            {
-                let mut display = String::new();
+        Err(Failure::Timeout {
+            number_of_attempts,
+            last_value,
+        })
+    }
+}
+
+/// Whether the objects of an expected condition are found with a `By`
+/// selector, like elements, or without it, like the location or the active
+/// element.
+///
+/// Returns an error if it combines conditions of both kinds.
+fn uses_selector(ec: &Ec) -> Result<bool, String> {
+    match ec {
+        Ec::And(a, b) | Ec::Or(a, b) => {
+            let (a_uses_selector, b_uses_selector) = (uses_selector(a)?, uses_selector(b)?);
+            if a_uses_selector == b_uses_selector {
+                Ok(a_uses_selector)
+            } else {
+                Err(format!(
+                    "conditions combined in {ec:?} can't mix conditions with and without selector"
+                ))
+            }
+        }
+        Ec::Not(a) => uses_selector(a),
+        _ => Target::of(ec).map(|target| {
+            matches!(
+                target,
+                Target::Node | Target::Element | Target::HtmlElement | Target::Elements
+            )
+        }),
+    }
+}
+
+/// Check that an expected condition can be evaluated before waiting for it.
+///
+/// Returns an error if it contains an invalid regular expression, selector,
+/// JSON Pointer or expected JSON value.
+fn validate(ec: &Ec) -> Result<(), String> {
+    match ec {
+        Ec::LocalStorageJsonPathIs(_, pointer, expected) => {
+            pointer_tokens(pointer)?;
+            js_sys::JSON::parse(expected).map(|_| ()).map_err(|error| {
+                format!(
+                    "invalid expected JSON value {expected:?}: {}",
+                    js_error_message(&error)
+                )
+            })
+        }
+        Ec::ActiveElementMatches(selector) => {
+            let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+                return Ok(());
+            };
+            document
+                .query_selector(selector)
+                .map(|_| ())
+                .map_err(|error| {
+                    format!(
+                        "invalid selector {selector:?}: {}",
+                        js_error_message(&error)
+                    )
+                })
+        }
+        Ec::HasClass(classes) | Ec::LacksClass(classes) if classes.is_empty() => {
+            Err(format!("{ec:?} requires at least one class"))
+        }
+        Ec::InnerTextMatches(pattern)
+        | Ec::AttributeValueMatches(_, pattern)
+        | Ec::CssValueMatches(_, pattern)
+        | Ec::UrlMatches(pattern) => new_regexp(pattern).map(|_| ()),
+        Ec::And(a, b) | Ec::Or(a, b) => validate(a).and_then(|()| validate(b)),
+        Ec::Not(a) => validate(a),
+        _ => Ok(()),
+    }
+}
+
+/// Replace sequences of whitespace by a single space and trim the text.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Check the value of an attribute of an element with a predicate.
+///
+/// It's not matched if the element does not have the attribute.
+fn check_attribute(
+    element: &web_sys::Element,
+    attribute: &str,
+    predicate: impl FnOnce(&str) -> bool,
+) -> Check {
+    match element.get_attribute(attribute) {
+        Some(attribute_value) => {
+            Check::new(predicate(&attribute_value), format!("{attribute_value:?}"))
+        }
+        None => Check::new(false, format!("attribute {attribute:?} not found")),
+    }
+}
+
+/// Check the computed value of a CSS property of an element with a predicate.
+fn check_css_value(
+    element: &web_sys::Element,
+    property: &str,
+    predicate: impl FnOnce(&str) -> bool,
+) -> Check {
+    let style = web_sys::window().and_then(|window| window.get_computed_style(element).ok()?);
+    match style.and_then(|style| style.get_property_value(property).ok()) {
+        Some(css_value) => Check::new(predicate(&css_value), format!("{css_value:?}")),
+        None => Check::new(false, "computed style not available"),
+    }
+}
+
+/// Check a part of `window.location` with a predicate.
+fn check_location(
+    part: &str,
+    value: Result<String, JsValue>,
+    predicate: impl FnOnce(&str) -> bool,
+) -> Check {
+    match value {
+        Ok(value) => Check::new(predicate(&value), format!("{value:?}")),
+        Err(_) => Check::new(false, format!("window.location.{part} not available")),
+    }
+}
 
-                if let Some(ref by) = self.condition.by {
-                    display.push_str(&format!("  - Selector: {by}\n"));
+/// Build the function that checks an expected condition against an object.
+fn ec_fn<T>(ec: &Ec) -> Box<dyn Fn(&T) -> Check + '_>
+where
+    T: 'static + web_sys::wasm_bindgen::JsCast,
+{
+    match ec {
+        Ec::InnerTextContains(text) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::HtmlElement>();
+            let inner_text = element.inner_text();
+            Check::new(inner_text.contains(text), format!("{inner_text:?}"))
+        }),
+        Ec::InnerTextIs(text, normalize) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::HtmlElement>();
+            let mut inner_text = element.inner_text();
+            if *normalize {
+                inner_text = normalize_whitespace(&inner_text);
+                Check::new(
+                    inner_text == normalize_whitespace(text),
+                    format!("{inner_text:?}"),
+                )
+            } else {
+                Check::new(inner_text == *text, format!("{inner_text:?}"))
+            }
+        }),
+        Ec::InnerTextMatches(pattern) => {
+            let regexp = new_regexp(pattern).ok();
+            Box::new(move |element: &T| {
+                let element = element.unchecked_ref::<web_sys::HtmlElement>();
+                let inner_text = element.inner_text();
+                let matched = regexp
+                    .as_ref()
+                    .is_some_and(|regexp| regexp.test(&inner_text));
+                Check::new(matched, format!("{inner_text:?}"))
+            })
+        }
+        Ec::TextContentContains(text) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            let text_content = element.text_content().unwrap_or_default();
+            Check::new(text_content.contains(text), format!("{text_content:?}"))
+        }),
+        Ec::AttributeValueIs(attribute, value) => Box::new(move |element: &T| {
+            check_attribute(element.unchecked_ref(), attribute, |attribute_value| {
+                attribute_value == value
+            })
+        }),
+        Ec::AttributeValueContains(attribute, value) => Box::new(move |element: &T| {
+            check_attribute(element.unchecked_ref(), attribute, |attribute_value| {
+                attribute_value.contains(value.as_str())
+            })
+        }),
+        Ec::AttributeValueStartsWith(attribute, value) => Box::new(move |element: &T| {
+            check_attribute(element.unchecked_ref(), attribute, |attribute_value| {
+                attribute_value.starts_with(value.as_str())
+            })
+        }),
+        Ec::AttributeValueMatches(attribute, pattern) => {
+            let regexp = new_regexp(pattern).ok();
+            Box::new(move |element: &T| {
+                check_attribute(element.unchecked_ref(), attribute, |attribute_value| {
+                    regexp
+                        .as_ref()
+                        .is_some_and(|regexp| regexp.test(attribute_value))
+                })
+            })
+        }
+        Ec::HasAttribute(attribute) => Box::new(move |element: &T| {
+            check_attribute(element.unchecked_ref(), attribute, |_| true)
+        }),
+        Ec::LacksAttribute(attribute) => Box::new(move |element: &T| {
+            let check = check_attribute(element.unchecked_ref(), attribute, |_| true);
+            Check {
+                matched: !check.matched,
+                ..check
+            }
+        }),
+        Ec::HasClass(classes) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            let class_list = element.class_list();
+            Check::new(
+                classes.iter().all(|class| class_list.contains(class)),
+                format!("{:?}", element.class_name()),
+            )
+        }),
+        Ec::LacksClass(classes) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            let class_list = element.class_list();
+            Check::new(
+                !classes.iter().any(|class| class_list.contains(class)),
+                format!("{:?}", element.class_name()),
+            )
+        }),
+        Ec::CssValueIs(property, value) => Box::new(move |element: &T| {
+            check_css_value(element.unchecked_ref(), property, |css_value| {
+                css_value == value
+            })
+        }),
+        Ec::CssValueContains(property, value) => Box::new(move |element: &T| {
+            check_css_value(element.unchecked_ref(), property, |css_value| {
+                css_value.contains(value.as_str())
+            })
+        }),
+        Ec::CssValueMatches(property, pattern) => {
+            let regexp = new_regexp(pattern).ok();
+            Box::new(move |element: &T| {
+                check_css_value(element.unchecked_ref(), property, |css_value| {
+                    regexp.as_ref().is_some_and(|regexp| regexp.test(css_value))
+                })
+            })
+        }
+        Ec::ValueIs(value) => {
+            Box::new(
+                move |element: &T| match form_control_value(element.unchecked_ref()) {
+                    Ok(control_value) => {
+                        Check::new(control_value == *value, format!("{control_value:?}"))
+                    }
+                    Err(reason) => Check::new(false, reason),
+                },
+            )
+        }
+        Ec::ValueContains(value) => {
+            Box::new(
+                move |element: &T| match form_control_value(element.unchecked_ref()) {
+                    Ok(control_value) => Check::new(
+                        control_value.contains(value.as_str()),
+                        format!("{control_value:?}"),
+                    ),
+                    Err(reason) => Check::new(false, reason),
+                },
+            )
+        }
+        Ec::IsChecked => Box::new(
+            move |element: &T| match is_checked(element.unchecked_ref()) {
+                Ok(true) => Check::new(true, "input is checked"),
+                Ok(false) => Check::new(false, "input is not checked"),
+                Err(reason) => Check::new(false, reason),
+            },
+        ),
+        Ec::IsSelected => Box::new(
+            move |element: &T| match is_selected(element.unchecked_ref()) {
+                Ok(true) => Check::new(true, "option is selected"),
+                Ok(false) => Check::new(false, "option is not selected"),
+                Err(reason) => Check::new(false, reason),
+            },
+        ),
+        Ec::HasFocus(within) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            let active = if *within {
+                active_element(element)
+            } else {
+                deepest_active_element(element)
+            };
+            match active {
+                Some(active) => Check::new(
+                    if *within {
+                        element.contains(Some(&active))
+                    } else {
+                        active == *element
+                    },
+                    format!("active element is {}", describe_element(&active)),
+                ),
+                None => Check::new(false, "no active element"),
+            }
+        }),
+        Ec::ActiveElementMatches(selector) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            Check::new(
+                element.matches(selector).unwrap_or(false),
+                format!("active element is {}", describe_element(element)),
+            )
+        }),
+        Ec::IsEnabled | Ec::IsDisabled => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            let disabled = element.matches(":disabled").unwrap_or(false);
+            let value = if disabled {
+                "element is disabled"
+            } else {
+                "element is enabled"
+            };
+            Check::new(disabled == matches!(ec, Ec::IsDisabled), value)
+        }),
+        Ec::StorageValueIs(_, attribute, value) => Box::new(move |storage: &T| {
+            let storage = storage.unchecked_ref::<web_sys::Storage>();
+            let attribute_value = storage.get_item(attribute);
+            if let Ok(Some(attribute_value)) = attribute_value {
+                Check::new(attribute_value == *value, format!("{attribute_value:?}"))
+            } else {
+                Check::new(false, format!("key {attribute:?} not found"))
+            }
+        }),
+        Ec::LocalStorageJsonPathIs(key, pointer, expected) => {
+            let tokens = pointer_tokens(pointer).unwrap_or_default();
+            let expected = js_sys::JSON::parse(expected).unwrap_or(JsValue::UNDEFINED);
+            Box::new(move |storage: &T| {
+                let storage = storage.unchecked_ref::<web_sys::Storage>();
+                let Ok(Some(text)) = storage.get_item(key) else {
+                    return Check::new(false, format!("key {key:?} not found"));
+                };
+                let value = match js_sys::JSON::parse(&text) {
+                    Ok(value) => value,
+                    Err(error) => {
+                        return Check::new(
+                            false,
+                            format!(
+                                "value of key {key:?} is not valid JSON ({}): {text:?}",
+                                js_error_message(&error)
+                            ),
+                        )
+                    }
+                };
+                match resolve_pointer(&value, &tokens) {
+                    Some(value) => Check::new(json_eq(&value, &expected), stringify(&value)),
+                    None => Check::new(
+                        false,
+                        format!(
+                            "JSON pointer {pointer:?} not found in {}",
+                            stringify(&value)
+                        ),
+                    ),
                 }
-                if let Some(ref ec) = self.condition.ec {
-                    display.push_str(&format!("  - Condition: {ec}\n"));
+            })
+        }
+        Ec::TitleIs(title) => Box::new(move |document: &T| {
+            let document_title = document.unchecked_ref::<web_sys::Document>().title();
+            Check::new(document_title == *title, format!("{document_title:?}"))
+        }),
+        Ec::TitleContains(title) => Box::new(move |document: &T| {
+            let document_title = document.unchecked_ref::<web_sys::Document>().title();
+            Check::new(
+                document_title.contains(title.as_str()),
+                format!("{document_title:?}"),
+            )
+        }),
+        Ec::ReadyStateIs(state) => Box::new(move |document: &T| {
+            let ready_state = document.unchecked_ref::<web_sys::Document>().ready_state();
+            Check::new(ready_state == state.as_str(), format!("{ready_state:?}"))
+        }),
+        Ec::StalenessOf(element) => Box::new(move |_| {
+            if element.is_connected() {
+                Check::new(false, "element is attached to the DOM")
+            } else {
+                Check::new(true, "element is detached from the DOM")
+            }
+        }),
+        Ec::FontsLoaded => Box::new(move |document: &T| {
+            let status = document
+                .unchecked_ref::<web_sys::Document>()
+                .fonts()
+                .status();
+            match status {
+                web_sys::FontFaceSetLoadStatus::Loaded => Check::new(true, "\"loaded\""),
+                _ => Check::new(false, "\"loading\""),
+            }
+        }),
+        Ec::CookieValueIs(name, value) => {
+            Box::new(
+                move |document: &T| match cookie_value(document.unchecked_ref(), name) {
+                    Ok(Some(cookie_value)) => {
+                        Check::new(cookie_value == *value, format!("{cookie_value:?}"))
+                    }
+                    Ok(None) => Check::new(false, format!("cookie {name:?} not found")),
+                    Err(reason) => Check::new(false, reason),
+                },
+            )
+        }
+        Ec::CookieExists(name) | Ec::CookieAbsent(name) => {
+            Box::new(
+                move |document: &T| match cookie_value(document.unchecked_ref(), name) {
+                    Ok(cookie_value) => Check::new(
+                        cookie_value.is_some() == matches!(ec, Ec::CookieExists(_)),
+                        if cookie_value.is_some() {
+                            format!("cookie {name:?} found")
+                        } else {
+                            format!("cookie {name:?} not found")
+                        },
+                    ),
+                    Err(reason) => Check::new(false, reason),
+                },
+            )
+        }
+        Ec::StorageHasKey(_, key) | Ec::StorageLacksKey(_, key) => Box::new(move |storage: &T| {
+            let storage = storage.unchecked_ref::<web_sys::Storage>();
+            let has_key = matches!(storage.get_item(key), Ok(Some(_)));
+            let value = if has_key {
+                format!("key {key:?} found")
+            } else {
+                format!("key {key:?} not found")
+            };
+            Check::new(has_key == matches!(ec, Ec::StorageHasKey(_, _)), value)
+        }),
+        Ec::LocationSearchIs(value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            check_location("search", location.search(), |search| search == value)
+        }),
+        Ec::LocationPathnameIs(value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            check_location("pathname", location.pathname(), |pathname| {
+                pathname == value
+            })
+        }),
+        Ec::LocationHashIs(value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            check_location("hash", location.hash(), |hash| hash == value)
+        }),
+        Ec::LocationHrefIs(value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            check_location("href", location.href(), |href| href == value)
+        }),
+        Ec::UrlContains(value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            check_location("href", location.href(), |href| {
+                href.contains(value.as_str())
+            })
+        }),
+        Ec::UrlMatches(pattern) => {
+            let regexp = new_regexp(pattern).ok();
+            Box::new(move |location: &T| {
+                let location = location.unchecked_ref::<web_sys::Location>();
+                check_location("href", location.href(), |href| {
+                    regexp.as_ref().is_some_and(|regexp| regexp.test(href))
+                })
+            })
+        }
+        Ec::SearchParamIs(key, value) => Box::new(move |location: &T| {
+            let location = location.unchecked_ref::<web_sys::Location>();
+            let param = location
+                .search()
+                .and_then(|search| web_sys::UrlSearchParams::new_with_str(&search));
+            match param.map(|params| params.get(key)) {
+                Ok(Some(param)) => Check::new(param == *value, format!("{param:?}")),
+                Ok(None) => Check::new(false, format!("search parameter {key:?} not found")),
+                Err(_) => Check::new(false, "window.location.search not available"),
+            }
+        }),
+        Ec::Custom(_, predicate) => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            Check {
+                matched: predicate(element),
+                value: None,
+            }
+        }),
+        Ec::IsVisible => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            match invisibility_reason(element) {
+                Some(reason) => Check::new(false, reason),
+                None => Check::new(true, "element is visible"),
+            }
+        }),
+        Ec::IsHidden => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            match invisibility_reason(element) {
+                Some(reason) => Check::new(true, reason),
+                None => Check::new(false, "element is visible"),
+            }
+        }),
+        Ec::CountIs(count) => Box::new(move |elements: &T| {
+            let length = elements.unchecked_ref::<js_sys::Array>().length() as usize;
+            Check::new(length == *count, format!("{length} elements"))
+        }),
+        Ec::CountAtLeast(count) => Box::new(move |elements: &T| {
+            let length = elements.unchecked_ref::<js_sys::Array>().length() as usize;
+            Check::new(length >= *count, format!("{length} elements"))
+        }),
+        Ec::CountAtMost(count) => Box::new(move |elements: &T| {
+            let length = elements.unchecked_ref::<js_sys::Array>().length() as usize;
+            Check::new(length <= *count, format!("{length} elements"))
+        }),
+        Ec::IsClickable => Box::new(move |element: &T| {
+            let element = element.unchecked_ref::<web_sys::Element>();
+            match unclickability_reason(element) {
+                Some(reason) => Check::new(false, reason),
+                None => Check::new(true, "element is clickable"),
+            }
+        }),
+        Ec::And(a, b) => {
+            let (a, b) = (ec_fn::<T>(a), ec_fn::<T>(b));
+            Box::new(move |object: &T| {
+                let (a, b) = (a(object), b(object));
+                Check {
+                    matched: a.matched && b.matched,
+                    value: join_values(a.value, b.value),
                 }
-                display
-            },
-            duration,
-            poll_frecuency,
-            number_of_attempts,
-        );
+            })
+        }
+        Ec::Or(a, b) => {
+            let (a, b) = (ec_fn::<T>(a), ec_fn::<T>(b));
+            Box::new(move |object: &T| {
+                let (a, b) = (a(object), b(object));
+                Check {
+                    matched: a.matched || b.matched,
+                    value: join_values(a.value, b.value),
+                }
+            })
+        }
+        Ec::Not(a) => {
+            let a = ec_fn::<T>(a);
+            Box::new(move |object: &T| {
+                let a = a(object);
+                Check {
+                    matched: !a.matched,
+                    value: a.value,
+                }
+            })
+        }
+    }
+}
+
+fn join_values(a: Option<String>, b: Option<String>) -> Option<String> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(format!("{a}, {b}")),
+        (a, b) => a.or(b),
     }
 }
@@ -0,0 +1,44 @@
+/// Reason why an element is not visible for the user, if any.
+///
+/// An element is considered visible when neither it nor any of its ancestors
+/// have the `hidden` attribute, its computed `display` is not `none`, its
+/// computed `visibility` is not `hidden`, its computed `opacity` is greater
+/// than zero and its bounding client rect has a non zero size.
+pub(crate) fn invisibility_reason(element: &web_sys::Element) -> Option<String> {
+    if let Ok(Some(hidden)) = element.closest("[hidden]") {
+        if hidden == *element {
+            return Some("element has the `hidden` attribute".to_string());
+        }
+        return Some(format!(
+            "ancestor <{}> has the `hidden` attribute",
+            hidden.tag_name().to_lowercase()
+        ));
+    }
+
+    let style = web_sys::window().and_then(|window| window.get_computed_style(element).ok()?);
+    if let Some(style) = style {
+        let display = style.get_property_value("display").unwrap_or_default();
+        if display == "none" {
+            return Some("computed display is \"none\"".to_string());
+        }
+        let visibility = style.get_property_value("visibility").unwrap_or_default();
+        if visibility == "hidden" {
+            return Some("computed visibility is \"hidden\"".to_string());
+        }
+        let opacity = style.get_property_value("opacity").unwrap_or_default();
+        if opacity.parse::<f64>().is_ok_and(|opacity| opacity <= 0.0) {
+            return Some(format!("computed opacity is {opacity:?}"));
+        }
+    }
+
+    let rect = element.get_bounding_client_rect();
+    if rect.width() == 0.0 || rect.height() == 0.0 {
+        return Some(format!(
+            "bounding client rect has zero size ({}x{})",
+            rect.width(),
+            rect.height()
+        ));
+    }
+
+    None
+}
@@ -1,4 +1,10 @@
-use crate::{until_impl, until_not_impl, Condition, WaitOptions};
+use crate::{
+    by::inner::By,
+    cast::{frame_element_has_type, is_frame_element},
+    single_element_error, until_impl, until_not_impl, Condition, WaitError, WaitOptions,
+    WaitStrategy,
+};
+use web_sys::wasm_bindgen::{JsCast, JsValue};
 
 /// Wait for a condition to be met.
 ///
@@ -14,6 +20,7 @@ where
 {
     Wait {
         options: options.into(),
+        frames: Vec::new(),
     }
 }
 
@@ -21,9 +28,45 @@ where
 #[derive(Debug)]
 pub struct Wait {
     pub(crate) options: WaitOptions,
+    pub(crate) frames: Vec<By>,
 }
 
 impl Wait {
+    /// Set the strategy used to decide when to check the condition again.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{Wait, WaitStrategy};
+    ///
+    /// Wait(1)
+    ///     .with_strategy(WaitStrategy::MutationObserver)
+    ///     .until("p#foo")
+    ///     .await;
+    /// ```
+    pub fn with_strategy(mut self, strategy: WaitStrategy) -> Self {
+        self.options = self.options.with_strategy(strategy);
+        self
+    }
+
+    /// Check the condition inside the same-origin `<iframe>` found by the
+    /// given selector.
+    ///
+    /// The frame is searched again in each attempt and the condition is not
+    /// checked until its document has been completely loaded. Call it several
+    /// times to search inside nested frames.
+    ///
+    /// ```rust,ignore
+    /// use web_sys_ec::{By, Ec, Wait};
+    ///
+    /// Wait(2)
+    ///     .in_frame(By::Id("editor"))
+    ///     .until(("p", Ec::InnerTextContains("Hello")))
+    ///     .await;
+    /// ```
+    pub fn in_frame(mut self, frame: By) -> Self {
+        self.frames.push(frame);
+        self
+    }
+
     /// Wait until the given condition is met.
     ///
     /// Panics with a detailed error message if the condition is not met
@@ -32,13 +75,16 @@ impl Wait {
     #[track_caller]
     #[allow(private_bounds)]
     pub async fn until(self, condition: impl Into<Condition>) {
-        until_impl(
+        if let Err(err) = until_impl(
             condition.into(),
             self,
             #[cfg(feature = "nightly")]
             std::panic::Location::caller(),
         )
-        .await;
+        .await
+        {
+            panic!("\n{err}");
+        }
     }
 
     /// Wait until the given condition is not met.
@@ -49,13 +95,146 @@ impl Wait {
     #[track_caller]
     #[allow(private_bounds)]
     pub async fn until_not(self, condition: impl Into<Condition>) {
+        if let Err(err) = until_not_impl(
+            condition.into(),
+            self,
+            #[cfg(feature = "nightly")]
+            std::panic::Location::caller(),
+        )
+        .await
+        {
+            panic!("\n{err}");
+        }
+    }
+
+    /// Wait until the given condition is met without panicking.
+    ///
+    /// Returns a [`WaitError`] if the condition is not met in the given time.
+    #[allow(ungated_async_fn_track_caller)]
+    #[track_caller]
+    #[allow(private_bounds)]
+    pub async fn try_until(self, condition: impl Into<Condition>) -> Result<(), WaitError> {
+        until_impl(
+            condition.into(),
+            self,
+            #[cfg(feature = "nightly")]
+            std::panic::Location::caller(),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Wait until the given condition is not met without panicking.
+    ///
+    /// Returns a [`WaitError`] if the condition is still meeting when the
+    /// given time expires.
+    #[allow(ungated_async_fn_track_caller)]
+    #[track_caller]
+    #[allow(private_bounds)]
+    pub async fn try_until_not(self, condition: impl Into<Condition>) -> Result<(), WaitError> {
         until_not_impl(
             condition.into(),
             self,
             #[cfg(feature = "nightly")]
             std::panic::Location::caller(),
         )
-        .await;
+        .await
+        .map(|_| ())
+    }
+
+    /// Wait until the given condition is met and return the matched element.
+    ///
+    /// The element is found with the `By` selector of the condition and it's
+    /// returned as a `web_sys::Element` whatever the condition is. Use
+    /// [`Wait::until_element_as`] to get a more specific type.
+    ///
+    /// Panics without waiting if the condition doesn't match a single
+    /// element, like conditions without selector such as
+    /// `Ec::LocationSearchIs` or count conditions such as `Ec::CountIs`.
+    /// Panics with a detailed error message if the condition is not met
+    /// in the given time.
+    #[allow(ungated_async_fn_track_caller)]
+    #[track_caller]
+    #[allow(private_bounds)]
+    pub async fn until_element(self, condition: impl Into<Condition>) -> web_sys::Element {
+        let condition = condition.into();
+        assert_single_element(&condition);
+        match until_impl(
+            condition,
+            self,
+            #[cfg(feature = "nightly")]
+            std::panic::Location::caller(),
+        )
+        .await
+        {
+            Ok(object) => cast_matched_object(object),
+            Err(err) => panic!("\n{err}"),
+        }
+    }
+
+    /// Wait until the given condition is met and return the matched element
+    /// casted to the type `T`.
+    ///
+    /// ```rust,ignore
+    /// use web_sys::HtmlElement;
+    /// use web_sys_ec::{Ec, Wait};
+    ///
+    /// let element = Wait(1)
+    ///     .until_element_as::<HtmlElement>(("p", Ec::InnerTextContains("text")))
+    ///     .await;
+    /// ```
+    ///
+    /// Panics without waiting if the condition doesn't match a single
+    /// element, like [`Wait::until_element`]. Panics with a detailed error
+    /// message if the condition is not met in the given time or if the
+    /// matched element can't be casted to `T`.
+    ///
+    /// Elements found inside frames are not instances of the constructors of
+    /// the main window, so their type is checked by their namespace and tag
+    /// name instead.
+    #[allow(ungated_async_fn_track_caller)]
+    #[track_caller]
+    #[allow(private_bounds)]
+    pub async fn until_element_as<T>(self, condition: impl Into<Condition>) -> T
+    where
+        T: JsCast,
+    {
+        let condition = condition.into();
+        assert_single_element(&condition);
+        match until_impl(
+            condition,
+            self,
+            #[cfg(feature = "nightly")]
+            std::panic::Location::caller(),
+        )
+        .await
+        {
+            Ok(object) => cast_matched_object(object),
+            Err(err) => panic!("\n{err}"),
+        }
+    }
+}
+
+fn assert_single_element(condition: &Condition) {
+    if let Some(reason) = single_element_error(condition) {
+        panic!("The condition can't return a matched element: {reason}");
+    }
+}
+
+fn cast_matched_object<T>(object: Option<JsValue>) -> T
+where
+    T: JsCast,
+{
+    let object = object.expect("A met condition always returns the matched object");
+    if is_frame_element(&object) && frame_element_has_type::<T>(object.unchecked_ref()) {
+        return object.unchecked_into();
+    }
+    match object.dyn_into::<T>() {
+        Ok(element) => element,
+        Err(object) => panic!(
+            "The matched object {object:?} can't be casted to `{}`",
+            std::any::type_name::<T>(),
+        ),
     }
 }
 
@@ -88,4 +267,10 @@ mod tests {
         assert_eq!(wait.options.duration().as_millis(), 2000);
         assert_eq!(wait.options.poll_frecuency().as_millis(), 1000);
     }
+
+    #[test]
+    #[should_panic(expected = "The condition can't return a matched element: ")]
+    fn until_element_count() {
+        assert_single_element(&("li", crate::Ec::CountIs(3)).into());
+    }
 }
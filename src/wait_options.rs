@@ -1,5 +1,24 @@
 use std::time::Duration;
 
+/// Strategy used to decide when to check the condition again.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStrategy {
+    /// Check the condition every poll frecuency interval.
+    #[default]
+    Polling,
+    /// Check the condition every time the DOM changes, observing the document
+    /// with a [`MutationObserver`], and a last time when the duration expires.
+    ///
+    /// Conditions without a `By` selector, like `Ec::LocalStorageAttributeValueIs`,
+    /// fall back to polling as their changes are not reflected in the DOM. So
+    /// do selectors that search inside shadow roots, like `By::Shadow`, waits
+    /// inside frames and conditions that change without DOM mutations, like
+    /// `Ec::HasFocus`, `Ec::ValueIs` or `Ec::CssValueIs`.
+    ///
+    /// [`MutationObserver`]: https://developer.mozilla.org/en-US/docs/Web/API/MutationObserver
+    MutationObserver,
+}
+
 /// Options for waiting.
 ///
 /// You don't need to create this struct directly as it offers
@@ -9,6 +28,7 @@ use std::time::Duration;
 pub struct WaitOptions {
     duration: Duration,
     poll_frecuency: Duration,
+    strategy: WaitStrategy,
 }
 
 impl Default for WaitOptions {
@@ -16,6 +36,7 @@ impl Default for WaitOptions {
         Self {
             duration: Duration::from_secs(10),
             poll_frecuency: Duration::from_millis(20),
+            strategy: WaitStrategy::default(),
         }
     }
 }
@@ -26,6 +47,7 @@ impl WaitOptions {
         Self {
             duration,
             poll_frecuency,
+            ..Default::default()
         }
     }
 
@@ -42,10 +64,19 @@ impl WaitOptions {
         self
     }
 
+    pub fn strategy(&self) -> WaitStrategy {
+        self.strategy
+    }
+
     pub fn with_poll_frecuency(mut self, poll_frecuency: Duration) -> Self {
         self.poll_frecuency = poll_frecuency;
         self
     }
+
+    pub fn with_strategy(mut self, strategy: WaitStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
 }
 
 impl From<u64> for WaitOptions {
@@ -54,6 +85,7 @@ impl From<u64> for WaitOptions {
         Self {
             duration: Duration::from_millis(millis),
             poll_frecuency: Duration::from_millis(millis / 50),
+            ..Default::default()
         }
     }
 }
@@ -63,6 +95,7 @@ impl From<(u64, u64)> for WaitOptions {
         Self {
             duration: Duration::from_secs(seconds),
             poll_frecuency: Duration::from_secs(poll_frecuency),
+            ..Default::default()
         }
     }
 }
@@ -73,6 +106,7 @@ impl From<f64> for WaitOptions {
         Self {
             duration: Duration::from_millis(millis),
             poll_frecuency: Duration::from_millis(millis / 50),
+            ..Default::default()
         }
     }
 }
@@ -84,6 +118,7 @@ impl From<(f64, f64)> for WaitOptions {
         Self {
             duration: Duration::from_millis(millis),
             poll_frecuency: Duration::from_millis(poll_millis),
+            ..Default::default()
         }
     }
 }
@@ -93,6 +128,7 @@ impl From<Duration> for WaitOptions {
         Self {
             duration,
             poll_frecuency: Duration::from_millis((duration.as_millis() / 20).try_into().unwrap()),
+            ..Default::default()
         }
     }
 }
@@ -102,6 +138,7 @@ impl From<(Duration, Duration)> for WaitOptions {
         Self {
             duration,
             poll_frecuency,
+            ..Default::default()
         }
     }
 }
@@ -111,6 +148,7 @@ impl From<(u64, f64)> for WaitOptions {
         Self {
             duration: Duration::from_millis(duration),
             poll_frecuency: Duration::from_millis((poll_frecuency * 1000.0).round() as u64),
+            ..Default::default()
         }
     }
 }
@@ -121,6 +159,7 @@ impl From<(f64, u64)> for WaitOptions {
         Self {
             duration: Duration::from_millis(millis),
             poll_frecuency: Duration::from_millis(poll_frecuency),
+            ..Default::default()
         }
     }
 }
@@ -130,6 +169,7 @@ impl From<(Duration, u64)> for WaitOptions {
         Self {
             duration,
             poll_frecuency: Duration::from_millis(poll_frecuency),
+            ..Default::default()
         }
     }
 }
@@ -139,6 +179,7 @@ impl From<(u64, Duration)> for WaitOptions {
         Self {
             duration: Duration::from_millis(duration),
             poll_frecuency,
+            ..Default::default()
         }
     }
 }
@@ -149,6 +190,7 @@ impl From<(f64, Duration)> for WaitOptions {
         Self {
             duration: Duration::from_millis(millis),
             poll_frecuency,
+            ..Default::default()
         }
     }
 }
@@ -158,6 +200,7 @@ impl From<(Duration, f64)> for WaitOptions {
         Self {
             duration,
             poll_frecuency: Duration::from_millis((poll_frecuency * 1000.0).round() as u64),
+            ..Default::default()
         }
     }
 }
@@ -81,3 +81,174 @@ pub async fn query_selector() {
 
     Wait(0.3).until_not("test4").await;
 }
+
+#[wasm_bindgen_test]
+pub async fn xpath() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("button").unwrap();
+        el.set_attribute("class", "test5").unwrap();
+        el.set_text_content(Some("  Save "));
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(By::XPath("//button[normalize-space()='Save']"))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document().query_selector(".test5").unwrap().unwrap();
+        document().body().unwrap().remove_child(&el).unwrap();
+    });
+
+    Wait(0.3)
+        .until_not(By::XPath("//button[normalize-space()='Save']"))
+        .await;
+
+    let err = Wait(0.2)
+        .try_until_not(By::XPath("//button["))
+        .await
+        .unwrap_err();
+    assert!(!err.is_timeout());
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .starts_with("invalid XPath expression \"//button[\""));
+}
+
+#[wasm_bindgen_test]
+pub async fn link_text() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("a").unwrap();
+        el.set_attribute("href", "#test6").unwrap();
+        el.set_attribute("class", "test6").unwrap();
+        el.set_text_content(Some(" Go to settings "));
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2).until(By::LinkText("Go to settings")).await;
+    Wait(0.2).until(By::PartialLinkText("settings")).await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document().query_selector(".test6").unwrap().unwrap();
+        document().body().unwrap().remove_child(&el).unwrap();
+    });
+
+    Wait(0.3).until_not(By::LinkText("Go to settings")).await;
+}
+
+#[wasm_bindgen_test]
+pub async fn within() {
+    let _tm = Timeout::new(5, move || {
+        for price in ["$1", "$2", "$3"] {
+            let card = document().create_element("div").unwrap();
+            card.set_attribute("class", "test7").unwrap();
+            let el = document().create_element("span").unwrap();
+            el.set_attribute("class", "price").unwrap();
+            el.set_text_content(Some(price));
+            card.append_child(&el).unwrap();
+            document().body().unwrap().append_child(&card).unwrap();
+        }
+    });
+
+    let el = Wait(0.2)
+        .until_element(By::Within(
+            By::QuerySelector(".test7:nth-of-type(3)"),
+            By::Class("price"),
+        ))
+        .await;
+    assert_eq!(el.text_content(), Some("$3".to_string()));
+
+    let el = Wait(0.1)
+        .until_element(By::Within(
+            By::QuerySelector(".test7:nth-of-type(3)"),
+            By::XPath(".//span"),
+        ))
+        .await;
+    assert_eq!(el.text_content(), Some("$3".to_string()));
+
+    let err = Wait(0.1)
+        .try_until(By::Within(
+            By::QuerySelector(".test7:nth-of-type(3)"),
+            By::XPath("//span"),
+        ))
+        .await
+        .unwrap_err();
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .starts_with("XPath expression \"//span\" is absolute"));
+
+    let _tm = Timeout::new(10, move || {
+        let cards = document().query_selector_all(".test7").unwrap();
+        for i in 0..cards.length() {
+            document()
+                .body()
+                .unwrap()
+                .remove_child(&cards.item(i).unwrap())
+                .unwrap();
+        }
+    });
+
+    Wait(0.3)
+        .until_not(By::Within(By::Class("test7"), By::Class("price")))
+        .await;
+}
+
+#[wasm_bindgen_test]
+pub async fn shadow() {
+    let _tm = Timeout::new(5, move || {
+        let host = document().create_element("div").unwrap();
+        host.set_attribute("class", "test8").unwrap();
+        let shadow_root = host
+            .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+            .unwrap();
+        let el = document().create_element("button").unwrap();
+        el.set_attribute("class", "inner").unwrap();
+        shadow_root.append_child(&el).unwrap();
+        document().body().unwrap().append_child(&host).unwrap();
+
+        let closed_host = document().create_element("div").unwrap();
+        closed_host.set_attribute("class", "test8-closed").unwrap();
+        closed_host
+            .attach_shadow(&web_sys::ShadowRootInit::new(
+                web_sys::ShadowRootMode::Closed,
+            ))
+            .unwrap();
+        document()
+            .body()
+            .unwrap()
+            .append_child(&closed_host)
+            .unwrap();
+    });
+
+    Wait(0.2)
+        .until(By::Shadow(By::Class("test8"), By::Class("inner")))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(By::Shadow(By::Class("test8-closed"), By::Class("inner")))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("shadow host <div> has no open shadow root, closed shadow roots are not reachable")
+    );
+
+    let err = Wait(0.1)
+        .try_until_not(By::Shadow(By::Class("test8-closed"), By::Class("inner")))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("shadow host <div> has no open shadow root, closed shadow roots are not reachable")
+    );
+    Wait(0.1)
+        .until_not(By::Shadow(By::Class("test8"), By::Class("missing")))
+        .await;
+
+    for selector in [".test8", ".test8-closed"] {
+        let el = document().query_selector(selector).unwrap().unwrap();
+        document().body().unwrap().remove_child(&el).unwrap();
+    }
+}
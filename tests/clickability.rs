@@ -0,0 +1,75 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use web_sys_ec::{By, Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn is_clickable() {
+    let fieldset = document().create_element("fieldset").unwrap();
+    fieldset.set_attribute("disabled", "").unwrap();
+    let el = document()
+        .create_element("button")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    el.set_id("is_clickable");
+    el.set_inner_text("Save");
+    fieldset.append_child(&el).unwrap();
+    document().body().unwrap().append_child(&fieldset).unwrap();
+
+    let err = Wait(0.1)
+        .try_until(("#is_clickable", Ec::IsClickable))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("element is disabled by an ancestor <fieldset disabled>")
+    );
+
+    let enabled_fieldset = fieldset.clone();
+    let _tm = Timeout::new(10, move || {
+        enabled_fieldset.remove_attribute("disabled").unwrap();
+    });
+
+    Wait(0.3).until(("#is_clickable", Ec::IsClickable)).await;
+
+    el.set_attribute("aria-disabled", "true").unwrap();
+    let err = Wait(0.1)
+        .try_until(("#is_clickable", Ec::IsClickable))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("element has `aria-disabled=\"true\"`")
+    );
+
+    document().body().unwrap().remove_child(&fieldset).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn is_clickable_in_shadow_root() {
+    let host = document().create_element("div").unwrap();
+    host.set_id("is_clickable_host");
+    let shadow_root = host
+        .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+        .unwrap();
+    let el = document()
+        .create_element("button")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    el.set_class_name("inner");
+    el.set_inner_text("Save");
+    shadow_root.append_child(&el).unwrap();
+    document().body().unwrap().append_child(&host).unwrap();
+
+    Wait(0.2)
+        .until((
+            By::Shadow(By::Id("is_clickable_host"), By::Class("inner")),
+            Ec::IsClickable,
+        ))
+        .await;
+
+    document().body().unwrap().remove_child(&host).unwrap();
+}
@@ -0,0 +1,38 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlDocument};
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn set_cookie(cookie: &str) {
+    document()
+        .unchecked_into::<HtmlDocument>()
+        .set_cookie(cookie)
+        .unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn cookie() {
+    let err = Wait(0.1)
+        .try_until(Ec::CookieExists("consent"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("cookie \"consent\" not found"));
+
+    let _tm = Timeout::new(10, move || {
+        set_cookie("consent=accepted; path=/");
+    });
+
+    Wait(0.3).until(Ec::CookieExists("consent")).await;
+    Wait(0.1)
+        .until(Ec::CookieValueIs("consent", "accepted"))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        set_cookie("consent=; path=/; max-age=0");
+    });
+
+    Wait(0.3).until(Ec::CookieAbsent("consent")).await;
+}
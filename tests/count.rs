@@ -0,0 +1,44 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys_ec::{By, Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn count() {
+    let _tm = Timeout::new(5, move || {
+        for _ in 0..3 {
+            let el = document().create_element("li").unwrap();
+            el.set_attribute("class", "count").unwrap();
+            el.set_attribute("id", "count_duplicated").unwrap();
+            document().body().unwrap().append_child(&el).unwrap();
+        }
+    });
+
+    Wait(0.2).until((By::Class("count"), Ec::CountIs(3))).await;
+    Wait(0.2).until(("li.count", Ec::CountAtLeast(2))).await;
+    Wait(0.2).until(("li.count", Ec::CountAtMost(3))).await;
+    Wait(0.2)
+        .until((By::Id("count_duplicated"), Ec::CountIs(3)))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("li.count", Ec::CountIs(4)))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("3 elements"));
+
+    let _tm = Timeout::new(10, move || {
+        let elements = document().query_selector_all("li.count").unwrap();
+        for i in 0..elements.length() {
+            document()
+                .body()
+                .unwrap()
+                .remove_child(&elements.item(i).unwrap())
+                .unwrap();
+        }
+    });
+
+    Wait(0.3).until(("li.count", Ec::CountIs(0))).await;
+}
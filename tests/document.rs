@@ -0,0 +1,39 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, ReadyState, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn title() {
+    let original_title = document().title();
+
+    let _tm = Timeout::new(10, move || {
+        document().set_title("Settings | My app");
+    });
+
+    Wait(0.3).until(Ec::TitleContains("Settings")).await;
+    Wait(0.1).until(Ec::TitleIs("Settings | My app")).await;
+
+    let err = Wait(0.1)
+        .try_until(Ec::TitleIs("Settings"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("\"Settings | My app\""));
+
+    document().set_title(&original_title);
+}
+
+#[wasm_bindgen_test]
+pub async fn ready_state_is() {
+    Wait(1).until(Ec::ReadyStateIs(ReadyState::Complete)).await;
+    Wait(0.1)
+        .until_not(Ec::ReadyStateIs(ReadyState::Loading))
+        .await;
+}
+
+#[wasm_bindgen_test]
+pub async fn fonts_loaded() {
+    Wait(1).until(Ec::FontsLoaded).await;
+}
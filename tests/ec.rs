@@ -67,3 +67,363 @@ pub async fn attribute_value_is() {
         .until_not(("attribute_value_is", Ec::AttributeValueIs("lang", "es")))
         .await;
 }
+
+#[wasm_bindgen_test]
+pub async fn and() {
+    let _tm = Timeout::new(5, move || {
+        let el = document()
+            .create_element("and")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.set_attribute("aria-busy", "true").unwrap();
+        el.set_inner_text("Save");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    let _tm2 = Timeout::new(20, move || {
+        let el = document().query_selector("and").unwrap().unwrap();
+        el.set_attribute("aria-busy", "false").unwrap();
+    });
+
+    Wait(0.3)
+        .until((
+            "and",
+            Ec::And(
+                Ec::AttributeValueIs("aria-busy", "false"),
+                Ec::InnerTextContains("Save"),
+            ),
+        ))
+        .await;
+
+    let el = document().query_selector("and").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn or() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("or").unwrap();
+        el.set_attribute("lang", "en").unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until((
+            "or",
+            Ec::Or(
+                Ec::AttributeValueIs("lang", "es"),
+                Ec::AttributeValueIs("lang", "en"),
+            ),
+        ))
+        .await;
+
+    let el = document().query_selector("or").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn not() {
+    let el = document()
+        .create_element("not")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    el.set_inner_text("Loading");
+    document().body().unwrap().append_child(&el).unwrap();
+
+    let _tm = Timeout::new(10, move || {
+        let el = document()
+            .query_selector("not")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.set_inner_text("Loaded");
+    });
+
+    Wait(0.3)
+        .until(("not", Ec::Not(Ec::InnerTextContains("Loading"))))
+        .await;
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn custom() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("custom").unwrap();
+        el.append_child(&document().create_element("li").unwrap())
+            .unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until((
+            "custom",
+            Ec::Custom("element has children", |element| {
+                element.child_element_count() > 0
+            }),
+        ))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until((
+            "custom",
+            Ec::Custom("element has many children", |element| {
+                element.child_element_count() > 1
+            }),
+        ))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains(
+        "  - Condition: element has many children (`Ec::Custom(\"element has many children\")`)\n"
+    ));
+
+    let el = document().query_selector("custom").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn inner_text_is() {
+    let _tm = Timeout::new(5, move || {
+        let el = document()
+            .create_element("inner_text_is")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.set_attribute("style", "white-space: pre").unwrap();
+        el.set_inner_text("Total:  3   items");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(("inner_text_is", Ec::InnerTextIs("Total:  3   items")))
+        .await;
+    Wait(0.1)
+        .until((
+            "inner_text_is",
+            Ec::NormalizedInnerTextIs(" Total: 3 items\n"),
+        ))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("inner_text_is", Ec::InnerTextIs("Total: 3 items")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("\"Total:  3   items\""));
+
+    let el = document().query_selector("inner_text_is").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn inner_text_matches() {
+    let _tm = Timeout::new(5, move || {
+        let el = document()
+            .create_element("inner_text_matches")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.set_inner_text("12 items");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(("inner_text_matches", Ec::InnerTextMatches(r"^\d+ items?$")))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("inner_text_matches", Ec::InnerTextMatches("^(\\d+")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .starts_with("invalid regular expression \"^(\\\\d+\": "));
+
+    let el = document()
+        .query_selector("inner_text_matches")
+        .unwrap()
+        .unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn text_content_contains() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("text_content_contains").unwrap();
+        el.set_inner_html("<span hidden>Select a language:</span>");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until((
+            "text_content_contains",
+            Ec::TextContentContains("Select a language:"),
+        ))
+        .await;
+
+    let el = document()
+        .query_selector("text_content_contains")
+        .unwrap()
+        .unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn attribute_presence() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("attribute_presence").unwrap();
+        el.set_attribute("aria-busy", "").unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(("attribute_presence", Ec::HasAttribute("aria-busy")))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document()
+            .query_selector("attribute_presence")
+            .unwrap()
+            .unwrap();
+        el.remove_attribute("aria-busy").unwrap();
+    });
+
+    Wait(0.3)
+        .until(("attribute_presence", Ec::LacksAttribute("aria-busy")))
+        .await;
+
+    let el = document()
+        .query_selector("attribute_presence")
+        .unwrap()
+        .unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn attribute_value_partial() {
+    let _tm = Timeout::new(5, move || {
+        let el = document()
+            .create_element("attribute_value_partial")
+            .unwrap();
+        el.set_attribute("href", "/es/docs/intro").unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until((
+            "attribute_value_partial",
+            Ec::AttributeValueStartsWith("href", "/es/"),
+        ))
+        .await;
+    Wait(0.1)
+        .until((
+            "attribute_value_partial",
+            Ec::AttributeValueContains("href", "docs"),
+        ))
+        .await;
+    Wait(0.1)
+        .until((
+            "attribute_value_partial",
+            Ec::AttributeValueMatches("href", r"^/\w+/docs/"),
+        ))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until((
+            "attribute_value_partial",
+            Ec::AttributeValueStartsWith("title", "Docs"),
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("attribute \"title\" not found"));
+
+    let el = document()
+        .query_selector("attribute_value_partial")
+        .unwrap()
+        .unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn has_class() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("has_class").unwrap();
+        el.set_class_name("tab loading");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(("has_class", Ec::HasClass("loading")))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document().query_selector("has_class").unwrap().unwrap();
+        el.set_class_name("is-active tab");
+    });
+
+    Wait(0.3)
+        .until(("has_class", Ec::HasClass("tab is-active")))
+        .await;
+    Wait(0.1)
+        .until(("has_class", Ec::LacksClass("loading error")))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("has_class", Ec::HasClass("tab error")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("\"is-active tab\""));
+
+    for ec in [Ec::HasClass("  "), Ec::LacksClass("")] {
+        let err = Wait(0.1).try_until(("has_class", ec)).await.unwrap_err();
+        assert_eq!(err.number_of_attempts(), 0);
+        assert!(err
+            .invalid_condition()
+            .unwrap()
+            .ends_with(" requires at least one class"));
+    }
+
+    let el = document().query_selector("has_class").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn css_value() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("css_value").unwrap();
+        el.set_attribute("style", "display: block; opacity: 0.5")
+            .unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .until(("css_value", Ec::CssValueIs("opacity", "0.5")))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document().query_selector("css_value").unwrap().unwrap();
+        el.set_attribute("style", "display: block; opacity: 1; color: red")
+            .unwrap();
+    });
+
+    Wait(0.3)
+        .until(("css_value", Ec::CssValueIs("opacity", "1")))
+        .await;
+    Wait(0.1)
+        .until(("css_value", Ec::CssValueContains("color", "255")))
+        .await;
+    Wait(0.1)
+        .until((
+            "css_value",
+            Ec::CssValueMatches("color", r"^rgb\(255, 0, 0\)$"),
+        ))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("css_value", Ec::CssValueIs("display", "none")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("\"block\""));
+
+    let el = document().query_selector("css_value").unwrap().unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
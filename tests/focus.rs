@@ -0,0 +1,119 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use web_sys_ec::{By, Ec, Wait, WaitOptions, WaitStrategy};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn has_focus() {
+    let dialog = document().create_element("div").unwrap();
+    dialog.set_id("has_focus");
+    dialog.set_inner_html("<input name='email'><button>Send</button>");
+    document().body().unwrap().append_child(&dialog).unwrap();
+
+    let err = Wait(0.1)
+        .try_until(("#has_focus input", Ec::HasFocus))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("active element is <body>"));
+
+    let _tm = Timeout::new(10, move || {
+        document()
+            .query_selector("#has_focus input")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<HtmlElement>()
+            .focus()
+            .unwrap();
+    });
+
+    Wait(WaitOptions::from(0.3).with_strategy(WaitStrategy::MutationObserver))
+        .until(("#has_focus input", Ec::HasFocus))
+        .await;
+    Wait(0.1).until(("#has_focus", Ec::HasFocusWithin)).await;
+    Wait(0.1).until_not(("#has_focus", Ec::HasFocus)).await;
+
+    document().body().unwrap().remove_child(&dialog).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn has_focus_in_shadow_root() {
+    let host = document().create_element("div").unwrap();
+    host.set_id("has_focus_host");
+    let shadow_root = host
+        .attach_shadow(&web_sys::ShadowRootInit::new(web_sys::ShadowRootMode::Open))
+        .unwrap();
+    shadow_root.set_inner_html("<input name='email'>");
+    document().body().unwrap().append_child(&host).unwrap();
+
+    shadow_root
+        .query_selector("input")
+        .unwrap()
+        .unwrap()
+        .unchecked_into::<HtmlElement>()
+        .focus()
+        .unwrap();
+
+    Wait(0.1)
+        .until((
+            By::Shadow(By::Id("has_focus_host"), By::TagName("input")),
+            Ec::HasFocus,
+        ))
+        .await;
+    Wait(0.1)
+        .until(("#has_focus_host", Ec::HasFocusWithin))
+        .await;
+    let err = Wait(0.1)
+        .try_until(("#has_focus_host", Ec::HasFocus))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("active element is <input>"));
+
+    document().body().unwrap().remove_child(&host).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn active_element_matches() {
+    let button = document()
+        .create_element("button")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    button.set_id("active_element_matches");
+    document().body().unwrap().append_child(&button).unwrap();
+
+    let focused_button = button.clone();
+    let _tm = Timeout::new(10, move || {
+        focused_button.focus().unwrap();
+    });
+
+    Wait(0.3)
+        .until(Ec::ActiveElementMatches("button#active_element_matches"))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(Ec::ActiveElementMatches("button["))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .starts_with("invalid selector \"button[\": "));
+
+    document().body().unwrap().remove_child(&button).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn active_element_matches_with_selector() {
+    let err = Wait(0.1)
+        .try_until(("body", Ec::ActiveElementMatches("button")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .ends_with(" doesn't take a selector"));
+}
@@ -0,0 +1,130 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlInputElement, HtmlOptionElement};
+use web_sys_ec::{Ec, Wait, WaitStrategy};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn value() {
+    let input = document()
+        .create_element("input")
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>();
+    input.set_id("value");
+    input.set_attribute("value", "initial").unwrap();
+    document().body().unwrap().append_child(&input).unwrap();
+
+    let typed_input = input.clone();
+    let _tm = Timeout::new(10, move || {
+        typed_input.set_value("user@example.com");
+    });
+
+    Wait(0.3)
+        .until(("#value", Ec::ValueIs("user@example.com")))
+        .await;
+    Wait(0.1)
+        .until(("#value", Ec::ValueContains("@example")))
+        .await;
+    Wait(0.1)
+        .until(("#value", Ec::AttributeValueIs("value", "initial")))
+        .await;
+
+    let typed_input = input.clone();
+    let _tm = Timeout::new(10, move || {
+        typed_input.set_value("admin@example.com");
+    });
+
+    Wait(0.3)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until(("#value", Ec::ValueIs("admin@example.com")))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(("body", Ec::ValueIs("")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("<body> element has no value"));
+
+    document().body().unwrap().remove_child(&input).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn is_checked() {
+    let input = document()
+        .create_element("input")
+        .unwrap()
+        .unchecked_into::<HtmlInputElement>();
+    input.set_id("is_checked");
+    input.set_type("checkbox");
+    document().body().unwrap().append_child(&input).unwrap();
+
+    let err = Wait(0.1)
+        .try_until(("#is_checked", Ec::IsChecked))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("input is not checked"));
+
+    let checked_input = input.clone();
+    let _tm = Timeout::new(10, move || {
+        checked_input.set_checked(true);
+    });
+
+    Wait(0.3).until(("#is_checked", Ec::IsChecked)).await;
+
+    let unchecked_input = input.clone();
+    let _tm = Timeout::new(10, move || {
+        unchecked_input.set_checked(false);
+    });
+
+    Wait(0.3)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until_not(("#is_checked", Ec::IsChecked))
+        .await;
+
+    document().body().unwrap().remove_child(&input).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn is_selected() {
+    let select = document().create_element("select").unwrap();
+    select.set_id("is_selected");
+    select.set_inner_html("<option value='en'>English</option><option value='es'>Español</option>");
+    document().body().unwrap().append_child(&select).unwrap();
+
+    let _tm = Timeout::new(10, move || {
+        document()
+            .query_selector("#is_selected option[value='es']")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<HtmlOptionElement>()
+            .set_selected(true);
+    });
+
+    Wait(0.3)
+        .until(("#is_selected option[value='es']", Ec::IsSelected))
+        .await;
+    Wait(0.1).until(("#is_selected", Ec::ValueIs("es"))).await;
+
+    document().body().unwrap().remove_child(&select).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn is_enabled() {
+    let fieldset = document().create_element("fieldset").unwrap();
+    fieldset.set_attribute("disabled", "").unwrap();
+    fieldset.set_inner_html("<button id='is_enabled'>Save</button>");
+    document().body().unwrap().append_child(&fieldset).unwrap();
+
+    Wait(0.1).until(("#is_enabled", Ec::IsDisabled)).await;
+
+    let enabled_fieldset = fieldset.clone();
+    let _tm = Timeout::new(10, move || {
+        enabled_fieldset.remove_attribute("disabled").unwrap();
+    });
+
+    Wait(0.3).until(("#is_enabled", Ec::IsEnabled)).await;
+
+    document().body().unwrap().remove_child(&fieldset).unwrap();
+}
@@ -0,0 +1,126 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::HtmlInputElement;
+use web_sys_ec::{By, Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn in_frame() {
+    let _tm = Timeout::new(5, move || {
+        let frame = document().create_element("iframe").unwrap();
+        frame.set_attribute("id", "in_frame").unwrap();
+        frame
+            .set_attribute("srcdoc", "<p lang=\"es\">Hola</p>")
+            .unwrap();
+        document().body().unwrap().append_child(&frame).unwrap();
+    });
+
+    Wait(1)
+        .in_frame(By::Id("in_frame"))
+        .until(("p", Ec::AttributeValueIs("lang", "es")))
+        .await;
+
+    let err = Wait(0.1)
+        .in_frame(By::Id("in_frame"))
+        .try_until(("p", Ec::InnerTextContains("Adiós")))
+        .await
+        .unwrap_err();
+    assert_eq!(err.frames().len(), 1);
+    assert!(err
+        .to_string()
+        .contains("  - Frame: HTML element with id 'in_frame' (`By::Id(\"in_frame\")`)\n"));
+
+    let err = Wait(0.1)
+        .in_frame(By::Id("in_frame_missing"))
+        .try_until("p")
+        .await
+        .unwrap_err();
+    assert!(err.last_value().unwrap().starts_with("no frame found: "));
+
+    let err = Wait(0.1)
+        .in_frame(By::Id("in_frame_missing"))
+        .try_until_not("p")
+        .await
+        .unwrap_err();
+    assert!(err.last_value().unwrap().starts_with("no frame found: "));
+    Wait(0.1)
+        .in_frame(By::Id("in_frame"))
+        .until_not("span")
+        .await;
+
+    let frame = document().get_element_by_id("in_frame").unwrap();
+    document().body().unwrap().remove_child(&frame).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn in_frame_read_element() {
+    let _tm = Timeout::new(5, move || {
+        let frame = document().create_element("iframe").unwrap();
+        frame.set_attribute("id", "in_frame_read").unwrap();
+        frame
+            .set_attribute(
+                "srcdoc",
+                "<p>Hola</p><input value=\"es\"><a href=\"#\">Idioma</a>",
+            )
+            .unwrap();
+        document().body().unwrap().append_child(&frame).unwrap();
+    });
+
+    Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until(("p", Ec::InnerTextContains("Hola")))
+        .await;
+    Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until(("input", Ec::ValueIs("es")))
+        .await;
+    Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until(By::LinkText("Idioma"))
+        .await;
+    Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until(("p", Ec::CountIs(1)))
+        .await;
+
+    let el = Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until_element(("p", Ec::InnerTextIs("Hola")))
+        .await;
+    assert_eq!(el.tag_name(), "P");
+
+    let input = Wait(1)
+        .in_frame(By::Id("in_frame_read"))
+        .until_element_as::<HtmlInputElement>(("input", Ec::ValueIs("es")))
+        .await;
+    assert_eq!(input.value(), "es");
+
+    let frame = document().get_element_by_id("in_frame_read").unwrap();
+    document().body().unwrap().remove_child(&frame).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn in_nested_frame() {
+    let _tm = Timeout::new(5, move || {
+        let frame = document().create_element("iframe").unwrap();
+        frame.set_attribute("id", "in_nested_frame").unwrap();
+        frame
+            .set_attribute(
+                "srcdoc",
+                "<iframe id=\"inner\" srcdoc=\"<p>Dentro</p>\"></iframe>",
+            )
+            .unwrap();
+        document().body().unwrap().append_child(&frame).unwrap();
+    });
+
+    Wait(2)
+        .in_frame(By::Id("in_nested_frame"))
+        .in_frame(By::Id("inner"))
+        .until(("p", Ec::InnerTextContains("Dentro")))
+        .await;
+
+    let frame = document().get_element_by_id("in_nested_frame").unwrap();
+    document().body().unwrap().remove_child(&frame).unwrap();
+}
@@ -0,0 +1,63 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::window;
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn replace_url(update: impl FnOnce(&web_sys::Url)) {
+    let url = web_sys::Url::new(
+        &window()
+            .location()
+            .href()
+            .expect("Failed to get location.href from the browser"),
+    )
+    .expect("Failed to parse location.href from the browser");
+    update(&url);
+    window()
+        .history()
+        .expect("Failed to get the history from the browser")
+        .replace_state_with_url(&web_sys::wasm_bindgen::JsValue::NULL, "", Some(&url.href()))
+        .expect("Failed to replace the history state");
+}
+
+#[wasm_bindgen_test]
+pub async fn location() {
+    let original_href = window().location().href().unwrap();
+    let pathname = window().location().pathname().unwrap();
+
+    let _tm = Timeout::new(10, move || {
+        replace_url(|url| {
+            url.set_search("?sort=name&page=2");
+            url.set_hash("#results");
+        });
+    });
+
+    Wait(0.3).until(Ec::LocationHashIs("#results")).await;
+    Wait(0.1).until(Ec::LocationPathnameIs(pathname)).await;
+    Wait(0.1).until(Ec::UrlContains("sort=name")).await;
+    Wait(0.1)
+        .until(Ec::UrlMatches(r"\?sort=\w+&page=\d+#"))
+        .await;
+    Wait(0.1).until(Ec::SearchParamIs("page", "2")).await;
+
+    let href = window().location().href().unwrap();
+    Wait(0.1).until(Ec::LocationHrefIs(href)).await;
+
+    let _tm = Timeout::new(10, move || {
+        replace_url(|url| url.set_search("?page=3&sort=name"));
+    });
+
+    Wait(0.3).until(Ec::SearchParamIs("page", "3")).await;
+
+    let err = Wait(0.1)
+        .try_until(Ec::SearchParamIs("filter", "new"))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("search parameter \"filter\" not found")
+    );
+
+    replace_url(|url| url.set_href(&original_href));
+}
@@ -0,0 +1,37 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn staleness_of() {
+    let el = document().create_element("p").unwrap();
+    el.set_id("staleness_of");
+    document().body().unwrap().append_child(&el).unwrap();
+
+    let row = Wait(0.1).until_element("#staleness_of").await;
+
+    let err = Wait(0.1)
+        .try_until(Ec::StalenessOf(row.clone()))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("element is attached to the DOM"));
+    assert!(err
+        .to_string()
+        .contains("`Ec::StalenessOf(<p id=\"staleness_of\">)`"));
+
+    let _tm = Timeout::new(10, move || {
+        let old = document().get_element_by_id("staleness_of").unwrap();
+        let new = document().create_element("p").unwrap();
+        new.set_id("staleness_of");
+        old.replace_with_with_node_1(&new).unwrap();
+    });
+
+    Wait(0.3).until(Ec::StalenessOf(row)).await;
+    Wait(0.1).until("#staleness_of").await;
+
+    let el = document().get_element_by_id("staleness_of").unwrap();
+    document().body().unwrap().remove_child(&el).unwrap();
+}
@@ -0,0 +1,106 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::window;
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, StorageKind, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn session_storage_value_is() {
+    let _tm = Timeout::new(5, move || {
+        window()
+            .session_storage()
+            .unwrap()
+            .unwrap()
+            .set_item("token", "abc")
+            .unwrap();
+    });
+
+    Wait(0.2)
+        .until(Ec::SessionStorageValueIs("token", "abc"))
+        .await;
+    Wait(0.1)
+        .until(Ec::StorageHasKey(StorageKind::Session, "token"))
+        .await;
+    Wait(0.1)
+        .until(Ec::StorageLacksKey(StorageKind::Local, "token"))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        window()
+            .session_storage()
+            .unwrap()
+            .unwrap()
+            .remove_item("token")
+            .unwrap();
+    });
+
+    Wait(0.3)
+        .until(Ec::StorageLacksKey(StorageKind::Session, "token"))
+        .await;
+
+    let err = Wait(0.1)
+        .try_until(Ec::SessionStorageValueIs("token", "abc"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("key \"token\" not found"));
+}
+
+#[wasm_bindgen_test]
+pub async fn local_storage_json_path_is() {
+    let _tm = Timeout::new(5, move || {
+        window()
+            .local_storage()
+            .unwrap()
+            .unwrap()
+            .set_item(
+                "state",
+                r#"{"cart": [], "user": {"theme": "dark", "id": 1}}"#,
+            )
+            .unwrap();
+    });
+
+    Wait(0.2)
+        .until(Ec::LocalStorageJsonPathIs(
+            "state",
+            "/user/theme",
+            r#""dark""#,
+        ))
+        .await;
+    Wait(0.1)
+        .until(Ec::LocalStorageJsonPathIs(
+            "state",
+            "",
+            r#"{"user":{"id":1.0,"theme":"dark"},"cart":[]}"#,
+        ))
+        .await;
+
+    window()
+        .local_storage()
+        .unwrap()
+        .unwrap()
+        .set_item("state", "{cart: []}")
+        .unwrap();
+
+    let err = Wait(0.1)
+        .try_until(Ec::LocalStorageJsonPathIs("state", "/cart", "[]"))
+        .await
+        .unwrap_err();
+    assert!(err
+        .last_value()
+        .unwrap()
+        .starts_with("value of key \"state\" is not valid JSON ("));
+
+    let err = Wait(0.1)
+        .try_until(Ec::LocalStorageJsonPathIs("state", "cart", "[]"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+
+    window()
+        .local_storage()
+        .unwrap()
+        .unwrap()
+        .remove_item("state")
+        .unwrap();
+}
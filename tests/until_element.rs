@@ -0,0 +1,46 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use web_sys_ec::{By, Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn until_element() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("p").unwrap();
+        el.set_attribute("id", "until_element1").unwrap();
+        el.set_attribute("lang", "es").unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    let el = Wait(0.2)
+        .until_element((By::Id("until_element1"), Ec::AttributeValueIs("lang", "es")))
+        .await;
+    assert_eq!(el.id(), "until_element1");
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn until_element_as() {
+    let _tm = Timeout::new(5, move || {
+        let el = document()
+            .create_element("until_element_as")
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.set_inner_text("Select a language:");
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    let el = Wait(0.2)
+        .until_element_as::<HtmlElement>((
+            "until_element_as",
+            Ec::InnerTextContains("Select a language:"),
+        ))
+        .await;
+    assert_eq!(el.inner_text(), "Select a language:");
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
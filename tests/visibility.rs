@@ -0,0 +1,67 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys::{wasm_bindgen::JsCast, HtmlElement};
+use web_sys_ec::{Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn is_visible() {
+    let el = document()
+        .create_element("is_visible")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    el.set_inner_text("Visible");
+    el.style().set_property("display", "none").unwrap();
+    document().body().unwrap().append_child(&el).unwrap();
+
+    let err = Wait(0.1)
+        .try_until(("is_visible", Ec::IsVisible))
+        .await
+        .unwrap_err();
+    assert_eq!(err.last_value(), Some("computed display is \"none\""));
+
+    let _tm = Timeout::new(10, move || {
+        let el = document()
+            .query_selector("is_visible")
+            .unwrap()
+            .unwrap()
+            .unchecked_into::<HtmlElement>();
+        el.style().set_property("display", "block").unwrap();
+    });
+
+    Wait(0.3).until(("is_visible", Ec::IsVisible)).await;
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn is_hidden() {
+    let parent = document().create_element("div").unwrap();
+    let el = document()
+        .create_element("is_hidden")
+        .unwrap()
+        .unchecked_into::<HtmlElement>();
+    el.set_inner_text("Hidden");
+    parent.append_child(&el).unwrap();
+    document().body().unwrap().append_child(&parent).unwrap();
+
+    let hidden_parent = parent.clone();
+    let _tm = Timeout::new(10, move || {
+        hidden_parent.set_attribute("hidden", "").unwrap();
+    });
+
+    Wait(0.3).until(("is_hidden", Ec::IsHidden)).await;
+
+    let err = Wait(0.1)
+        .try_until(("is_hidden", Ec::IsVisible))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("ancestor <div> has the `hidden` attribute")
+    );
+
+    document().body().unwrap().remove_child(&parent).unwrap();
+}
@@ -0,0 +1,126 @@
+use gloo_utils::document;
+use wasm_bindgen_test::*;
+use web_sys_ec::{By, Ec, Wait};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn try_until() {
+    let err = Wait(0.1)
+        .try_until(By::Id("wait_error1"))
+        .await
+        .unwrap_err();
+
+    assert!(err.selector().is_some());
+    assert!(err.condition().is_none());
+    assert_eq!(err.duration().as_millis(), 100);
+    assert!(err.number_of_attempts() > 1);
+    assert_eq!(err.last_value(), Some("no element found"));
+    assert!(err
+        .to_string()
+        .starts_with("Expected condition has not been met in the given time:\n"));
+}
+
+#[wasm_bindgen_test]
+pub async fn try_until_not() {
+    let el = document().create_element("wait_error2").unwrap();
+    el.set_attribute("lang", "es").unwrap();
+    document().body().unwrap().append_child(&el).unwrap();
+
+    let err = Wait(0.1)
+        .try_until_not(("wait_error2", Ec::AttributeValueIs("lang", "es")))
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.last_value(), Some("\"es\""));
+    assert!(err.to_string().contains("  - Last value: \"es\"\n"));
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn try_until_not_html_element() {
+    let svg = document()
+        .create_element_ns(Some("http://www.w3.org/2000/svg"), "svg")
+        .unwrap();
+    svg.set_id("try_until_not_html_element");
+    svg.set_inner_html("<text>Hola</text>");
+    document().body().unwrap().append_child(&svg).unwrap();
+
+    let err = Wait(0.1)
+        .try_until((
+            "#try_until_not_html_element text",
+            Ec::InnerTextContains("Hola"),
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.last_value(),
+        Some("element <text> is not an HTML element")
+    );
+
+    document().body().unwrap().remove_child(&svg).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn try_until_mixed_objects() {
+    let err = Wait(0.1)
+        .try_until(Ec::And(
+            Ec::LocationSearchIs("?lang=es"),
+            Ec::LocalStorageAttributeValueIs("lang", "es"),
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .ends_with("are checked over different objects so they can't be combined"));
+
+    let err = Wait(0.1)
+        .try_until(("li", Ec::Or(Ec::CountIs(0), Ec::IsVisible)))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+
+    let row = document().create_element("tr").unwrap();
+    for ec in [
+        Ec::And(
+            Ec::StalenessOf(row.clone()),
+            Ec::ActiveElementMatches("#save"),
+        ),
+        Ec::And(
+            Ec::ActiveElementMatches("#save"),
+            Ec::StalenessOf(row.clone()),
+        ),
+        Ec::Or(
+            Ec::StalenessOf(row.clone()),
+            Ec::StalenessOf(document().create_element("tr").unwrap()),
+        ),
+    ] {
+        let err = Wait(0.1).try_until(ec).await.unwrap_err();
+        assert_eq!(err.number_of_attempts(), 0);
+    }
+}
+
+#[wasm_bindgen_test]
+pub async fn try_until_without_selector() {
+    let err = Wait(0.1)
+        .try_until(Ec::InnerTextContains("Hola"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 0);
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .starts_with("expected condition HTML element innerText contains the text \"Hola\""));
+    assert!(err
+        .invalid_condition()
+        .unwrap()
+        .ends_with(" requires a selector"));
+    assert!(!err.is_timeout());
+    assert!(err
+        .to_string()
+        .starts_with("Expected condition can't be checked:\n"));
+    assert!(!err.to_string().contains("  - Duration: "));
+}
@@ -0,0 +1,105 @@
+use gloo_timers::callback::Timeout;
+use gloo_utils::{document, window};
+use wasm_bindgen_test::*;
+use web_sys_ec::{Ec, Wait, WaitStrategy};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+pub async fn mutation_observer() {
+    let _tm = Timeout::new(5, move || {
+        let el = document().create_element("mutation_observer").unwrap();
+        document().body().unwrap().append_child(&el).unwrap();
+    });
+
+    Wait(0.2)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until("mutation_observer")
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document()
+            .query_selector("mutation_observer")
+            .unwrap()
+            .unwrap();
+        el.set_attribute("lang", "es").unwrap();
+    });
+
+    Wait(0.3)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until(("mutation_observer", Ec::AttributeValueIs("lang", "es")))
+        .await;
+
+    let _tm = Timeout::new(10, move || {
+        let el = document()
+            .query_selector("mutation_observer")
+            .unwrap()
+            .unwrap();
+        document().body().unwrap().remove_child(&el).unwrap();
+    });
+
+    Wait(0.3)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until_not("mutation_observer")
+        .await;
+}
+
+#[wasm_bindgen_test]
+pub async fn mutation_observer_checks_on_mutations() {
+    let el = document()
+        .create_element("mutation_observer_checks")
+        .unwrap();
+    document().body().unwrap().append_child(&el).unwrap();
+
+    // Without DOM changes, the condition is only checked again at the deadline.
+    let err = Wait(0.2)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .try_until((
+            "mutation_observer_checks",
+            Ec::AttributeValueIs("lang", "es"),
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 2);
+
+    let changed_el = el.clone();
+    let _tm = Timeout::new(10, move || {
+        changed_el.set_attribute("data-changed", "").unwrap();
+    });
+
+    let err = Wait(0.2)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .try_until((
+            "mutation_observer_checks",
+            Ec::AttributeValueIs("lang", "es"),
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(err.number_of_attempts(), 3);
+
+    document().body().unwrap().remove_child(&el).unwrap();
+}
+
+#[wasm_bindgen_test]
+pub async fn mutation_observer_falls_back_to_polling() {
+    let _tm = Timeout::new(5, move || {
+        window()
+            .local_storage()
+            .unwrap()
+            .unwrap()
+            .set_item("mutation_observer", "es")
+            .unwrap();
+    });
+
+    Wait(0.2)
+        .with_strategy(WaitStrategy::MutationObserver)
+        .until(Ec::LocalStorageAttributeValueIs("mutation_observer", "es"))
+        .await;
+
+    window()
+        .local_storage()
+        .unwrap()
+        .unwrap()
+        .remove_item("mutation_observer")
+        .unwrap();
+}